@@ -1,6 +1,221 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+include!("src/compiled_layout.rs");
+
+#[derive(Default)]
+struct Encoder {
+    strings: Vec<String>,
+    interned: HashMap<String, u32>,
+    body: Vec<u8>,
+}
+
+impl Encoder {
+    fn intern(&mut self, s: &str) -> u32 {
+        if let Some(&index) = self.interned.get(s) {
+            return index;
+        }
+        let index = self.strings.len() as u32;
+        self.strings.push(s.to_string());
+        self.interned.insert(s.to_string(), index);
+        index
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.body.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.body.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn str(&mut self, value: Option<&str>) {
+        let index = value.map_or(NONE, |s| self.intern(s));
+        self.u32(index);
+    }
+
+    fn opt_i32(&mut self, value: Option<i64>) {
+        self.body.push(value.is_some() as u8);
+        self.i32(value.map_or(0, to_i32));
+    }
+
+    fn opt_i64(&mut self, value: Option<i64>) {
+        self.body.push(value.is_some() as u8);
+        self.body.extend_from_slice(&value.unwrap_or(0).to_le_bytes());
+    }
+
+    fn opt_f64(&mut self, value: Option<f64>) {
+        self.body.push(value.is_some() as u8);
+        self.body.extend_from_slice(&value.unwrap_or(0.0).to_le_bytes());
+    }
+
+    // One byte per value, `u8::MAX` when absent.
+    fn variant(&mut self, keys: &[&str], value: Option<&str>) {
+        let index = value.map_or(u8::MAX, |v| {
+            keys.iter().position(|k| *k == v).unwrap_or_else(|| panic!("unknown value \"{}\"", v)) as u8
+        });
+        self.body.push(index);
+    }
+
+    fn name(&mut self, name: &Value) {
+        let name = name.as_object().expect("name must be an object");
+        for key in LANGUAGE_KEYS {
+            let value = name.get(key).and_then(Value::as_str);
+            self.str(value);
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.body.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&(self.strings.len() as u32).to_le_bytes());
+        for s in &self.strings {
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        out.extend_from_slice(&self.body);
+        out
+    }
+}
+
+// Refuses values the decoder would read back as something else.
+fn to_i32(value: i64) -> i32 {
+    i32::try_from(value).unwrap_or_else(|_| panic!("{} does not fit in an i32", value))
+}
+
+fn read_json(path: &str) -> Map<String, Value> {
+    println!("cargo:rerun-if-changed={}", path);
+    let text = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    match serde_json::from_str(&text) {
+        Ok(Value::Object(map)) => map,
+        Ok(_) => panic!("{}: expected a JSON object", path),
+        Err(e) => panic!("{}: {}", path, e),
+    }
+}
+
+fn sorted_keys(map: &Map<String, Value>) -> Vec<&String> {
+    let mut keys: Vec<_> = map.keys().collect();
+    keys.sort();
+    keys
+}
+
+fn compile_mayhem(mayhem: &Map<String, Value>) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+
+    let mut augments: Vec<&Value> = mayhem["augments"].as_array().expect("augments must be an array").iter().collect();
+    augments.sort_by_key(|a| a["id"].as_i64());
+
+    encoder.u32(augments.len() as u32);
+    for augment in augments {
+        encoder.i32(to_i32(augment["id"].as_i64().expect("augment id")));
+        encoder.name(&augment["name"]);
+        encoder.str(augment.get("tier").and_then(Value::as_str));
+        encoder.str(augment.get("popularity").and_then(Value::as_str));
+        encoder.opt_i32(augment.get("games").and_then(Value::as_i64));
+        encoder.opt_f64(augment.get("win_rate").and_then(Value::as_f64));
+        encoder.variant(&RARITY_KEYS, augment.get("rarity").and_then(Value::as_str));
+    }
+
+    let champions = mayhem["champions"].as_object().expect("champions must be an object");
+    encoder.u32(champions.len() as u32);
+    for key in sorted_keys(champions) {
+        let entries = champions[key].as_array().expect("champion stats must be an array");
+        encoder.str(Some(key));
+        encoder.u32(entries.len() as u32);
+        for entry in entries {
+            encoder.i32(to_i32(entry["id"].as_i64().expect("champion augment id")));
+            encoder.str(Some(entry["tier"].as_str().expect("champion tier")));
+            encoder.str(Some(entry["popularity"].as_str().expect("champion popularity")));
+            encoder.i32(to_i32(entry["games"].as_i64().expect("champion games")));
+            encoder.opt_f64(entry.get("win_rate").and_then(Value::as_f64));
+        }
+    }
+
+    match mayhem.get("metadata").and_then(Value::as_object) {
+        Some(metadata) => {
+            encoder.body.push(1);
+            encoder.str(metadata.get("patch").and_then(Value::as_str));
+            encoder.str(metadata.get("collected_from").and_then(Value::as_str));
+            encoder.str(metadata.get("collected_to").and_then(Value::as_str));
+            encoder.opt_i64(metadata.get("total_games").and_then(Value::as_i64));
+            encoder.str(metadata.get("source").and_then(Value::as_str));
+        }
+        None => encoder.body.push(0),
+    }
+
+    let synergies = mayhem.get("synergies").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    encoder.u32(synergies.len() as u32);
+    for synergy in synergies {
+        let pair = synergy["augments"].as_array().expect("synergy augments must be an array");
+        assert_eq!(pair.len(), 2, "synergy augments must be a pair");
+        encoder.i32(to_i32(pair[0].as_i64().expect("synergy augment id")));
+        encoder.i32(to_i32(pair[1].as_i64().expect("synergy augment id")));
+        encoder.str(Some(synergy["tier"].as_str().expect("synergy tier")));
+        encoder.i32(to_i32(synergy["games"].as_i64().expect("synergy games")));
+        encoder.opt_f64(synergy.get("win_rate").and_then(Value::as_f64));
+    }
+
+    encoder.finish()
+}
+
+fn compile_champions(champions: &Map<String, Value>) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+
+    encoder.u32(champions.len() as u32);
+    for key in sorted_keys(champions) {
+        let champion = &champions[key];
+        encoder.str(Some(key));
+        encoder.name(&champion["name"]);
+        encoder.opt_i32(champion.get("key").and_then(Value::as_i64));
+
+        let classes = champion.get("classes").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+        encoder.body.push(classes.len() as u8);
+        for class in classes {
+            encoder.variant(&CLASS_KEYS, class.as_str());
+        }
+        encoder.variant(&DAMAGE_KEYS, champion.get("damage_type").and_then(Value::as_str));
+        encoder.variant(&RANGE_KEYS, champion.get("range").and_then(Value::as_str));
+    }
+
+    encoder.finish()
+}
+
+const RIOT_ROOT_URL: &str = "https://static.developer.riotgames.com/docs/lol/riotgames.pem";
+
 fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/compiled_layout.rs");
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR");
+    let out_dir = Path::new(&out_dir);
+
+    let mayhem = compile_mayhem(&read_json("data/mayhem.json"));
+    std::fs::write(out_dir.join("mayhem.bin"), mayhem).expect("Failed to write mayhem.bin");
+
+    let champions = compile_champions(&read_json("data/champions.json"));
+    std::fs::write(out_dir.join("champions.bin"), champions).expect("Failed to write champions.bin");
+
+    // Pinned by `src/tls.rs`. Without it the build still succeeds and the app reports the missing root
+    // in the tray when it tries to reach the game.
+    println!("cargo:rerun-if-changed=data/riotgames.pem");
+    println!("cargo::rustc-check-cfg=cfg(riot_root_ca)");
+    let riot_root = match std::fs::read("data/riotgames.pem") {
+        Ok(pem) => {
+            println!("cargo:rustc-cfg=riot_root_ca");
+            pem
+        }
+        Err(_) => {
+            println!("cargo:warning=data/riotgames.pem is missing; download it from {}", RIOT_ROOT_URL);
+            Vec::new()
+        }
+    };
+    std::fs::write(out_dir.join("riotgames.pem"), riot_root).expect("Failed to write riotgames.pem");
+
     #[cfg(windows)]
     {
+        println!("cargo:rerun-if-changed=data/icon.ico");
         let mut res = winresource::WindowsResource::new();
         res.set_icon("data/icon.ico");
         res.compile().expect("Failed to compile resources");
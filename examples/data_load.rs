@@ -0,0 +1,63 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use aram_mayhem_advisor::compiled::{decode_champions, decode_mayhem, CHAMPIONS_BIN, MAYHEM_BIN};
+use aram_mayhem_advisor::data::{ChampionInfo, MayhemData};
+
+const MAYHEM_JSON: &str = include_str!("../data/mayhem.json");
+const CHAMPIONS_JSON: &str = include_str!("../data/champions.json");
+const RUNS: u32 = 20;
+
+struct Counting;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn measure<T>(label: &str, embedded: usize, load: impl Fn() -> T) {
+    let mut total = Duration::ZERO;
+    let mut retained = 0;
+
+    for _ in 0..RUNS {
+        let before = LIVE.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let value = load();
+        total += start.elapsed();
+        retained = LIVE.load(Ordering::Relaxed) - before;
+        drop(value);
+    }
+
+    println!(
+        "{:<24} {:>8.2} ms  {:>8} KiB heap  {:>8} KiB embedded",
+        label,
+        total.as_secs_f64() * 1000.0 / RUNS as f64,
+        retained / 1024,
+        embedded / 1024
+    );
+}
+
+fn main() {
+    measure("mayhem.json (serde)", MAYHEM_JSON.len(), || {
+        serde_json::from_str::<MayhemData>(MAYHEM_JSON).unwrap()
+    });
+    measure("mayhem.bin (compiled)", MAYHEM_BIN.len(), || decode_mayhem(MAYHEM_BIN).unwrap());
+    measure("champions.json (serde)", CHAMPIONS_JSON.len(), || {
+        serde_json::from_str::<HashMap<String, ChampionInfo>>(CHAMPIONS_JSON).unwrap()
+    });
+    measure("champions.bin (compiled)", CHAMPIONS_BIN.len(), || decode_champions(CHAMPIONS_BIN).unwrap());
+}
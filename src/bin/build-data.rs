@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aram_mayhem_advisor::data::MayhemData;
+use aram_mayhem_advisor::ingest::{build_mayhem_data, read_rows, AugmentStatsRow, BuildOptions, ChampionStatsRow, SynergyStatsRow};
+use aram_mayhem_advisor::metadata::{parse_date, DataMetadata};
+
+const USAGE: &str = "usage: build-data --augments <file.csv|json> --champions <file.csv|json> \
+--output <file.json> --patch 15.22 --to YYYY-MM-DD [--from YYYY-MM-DD] [--total-games N] \
+[--source NAME] [--synergies <file.csv|json>] [--names data/mayhem.json] [--min-games 100]";
+
+struct Args {
+    augments: PathBuf,
+    champions: PathBuf,
+    synergies: Option<PathBuf>,
+    names: PathBuf,
+    output: PathBuf,
+    options: BuildOptions,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut augments = None;
+    let mut champions = None;
+    let mut synergies = None;
+    let mut names = PathBuf::from("data/mayhem.json");
+    let mut output = None;
+    let mut options = BuildOptions::default();
+    let mut metadata = DataMetadata::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--augments" => augments = Some(PathBuf::from(value()?)),
+            "--champions" => champions = Some(PathBuf::from(value()?)),
+            "--synergies" => synergies = Some(PathBuf::from(value()?)),
+            "--names" => names = PathBuf::from(value()?),
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "--min-games" => {
+                options.min_games = value()?.parse().map_err(|e| format!("--min-games: {}", e))?;
+            }
+            "--patch" => metadata.patch = Some(value()?),
+            "--from" => metadata.collected_from = Some(date(&arg, value()?)?),
+            "--to" => metadata.collected_to = Some(date(&arg, value()?)?),
+            "--source" => metadata.source = Some(value()?),
+            "--total-games" => {
+                metadata.total_games = Some(value()?.parse().map_err(|e| format!("--total-games: {}", e))?);
+            }
+            _ => return Err(format!("unknown argument: {}", arg)),
+        }
+    }
+
+    // The app warns about a patch mismatch or stale data from these, so every build records them.
+    if metadata.patch.is_none() {
+        return Err("--patch is required".to_string());
+    }
+    if metadata.collected_to.is_none() {
+        return Err("--to is required".to_string());
+    }
+    options.metadata = Some(metadata);
+
+    Ok(Args {
+        augments: augments.ok_or("--augments is required")?,
+        champions: champions.ok_or("--champions is required")?,
+        synergies,
+        names,
+        output: output.ok_or("--output is required")?,
+        options,
+    })
+}
+
+fn date(arg: &str, value: String) -> Result<String, String> {
+    match parse_date(&value) {
+        Some(_) => Ok(value),
+        None => Err(format!("{}: expected YYYY-MM-DD, got {}", arg, value)),
+    }
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let names_text = std::fs::read_to_string(&args.names)
+        .map_err(|e| format!("{}: {}", args.names.display(), e))?;
+    let names: MayhemData = serde_json::from_str(&names_text)
+        .map_err(|e| format!("{}: {}", args.names.display(), e))?;
+
+    let augment_rows: Vec<AugmentStatsRow> = read_rows(&args.augments).map_err(|e| e.to_string())?;
+    let champion_rows: Vec<ChampionStatsRow> = read_rows(&args.champions).map_err(|e| e.to_string())?;
+
+    let synergy_rows: Vec<SynergyStatsRow> = match &args.synergies {
+        Some(path) => read_rows(path).map_err(|e| e.to_string())?,
+        None => Vec::new(),
+    };
+
+    let data = build_mayhem_data(&names, &augment_rows, &champion_rows, &synergy_rows, &args.options)
+        .map_err(|e| e.to_string())?;
+
+    let json = serde_json::to_string_pretty(&data).map_err(|e| e.to_string())?;
+    std::fs::write(&args.output, json).map_err(|e| format!("{}: {}", args.output.display(), e))?;
+
+    println!(
+        "wrote {} augments, {} champions and {} synergies to {}",
+        data.augments.len(),
+        data.champions.len(),
+        data.synergies.len(),
+        args.output.display()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
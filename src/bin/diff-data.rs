@@ -0,0 +1,60 @@
+use std::process::ExitCode;
+
+use aram_mayhem_advisor::data::MayhemData;
+use aram_mayhem_advisor::diff::{diff, render, DiffFormat, DiffOptions};
+
+const USAGE: &str = "usage: diff-data <old.json> <new.json> [--format text|json|markdown] [--lang ko_KR] \
+[--min-games N] [--popularity-threshold PP] [--games-threshold RATIO]";
+
+fn load(path: &str) -> Result<MayhemData, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn run() -> Result<(), String> {
+    let mut paths = Vec::new();
+    let mut format = DiffFormat::Text;
+    let mut options = DiffOptions::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            paths.push(arg);
+            continue;
+        }
+
+        let value = args.next().ok_or_else(|| format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--format" => format = value.parse()?,
+            "--lang" => options.language = value.parse().map_err(|e| format!("--lang: {}", e))?,
+            "--min-games" => options.min_games = value.parse().map_err(|e| format!("--min-games: {}", e))?,
+            "--popularity-threshold" => {
+                options.popularity_threshold = value.parse().map_err(|e| format!("--popularity-threshold: {}", e))?;
+            }
+            "--games-threshold" => {
+                options.games_ratio_threshold = value.parse().map_err(|e| format!("--games-threshold: {}", e))?;
+            }
+            _ => return Err(format!("unknown argument: {}", arg)),
+        }
+    }
+
+    let [old_path, new_path] = paths.as_slice() else {
+        return Err("expected exactly two data files".to_string());
+    };
+
+    let old = load(old_path)?;
+    let new = load(new_path)?;
+
+    print!("{}", render(&diff(&old, &new, &options), format));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::io::{self, BufReader, Write};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::{self, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+
+const USAGE: &str = "usage: mock-live-client <timeline.json> [--port 2999] [--speed 1] \
+[--cert tests/fixtures/tls/mock.crt] [--key tests/fixtures/tls/mock.key]";
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+// A scripted game: each step serves its fixture for `seconds`, then the server exits like a closed game.
+#[derive(Deserialize)]
+struct TimelineFile {
+    #[serde(default = "default_version")]
+    version: String,
+    steps: Vec<StepFile>,
+}
+
+#[derive(Deserialize)]
+struct StepFile {
+    seconds: f64,
+    // `allgamedata` body, relative to the timeline; without one the API answers 404 like during loading.
+    #[serde(default)]
+    fixture: Option<PathBuf>,
+    // Overrides `gameData.gameTime` at the start of the step; it then advances in real time.
+    #[serde(default)]
+    game_time: Option<f64>,
+    // Appended to the fixture's `events.Events`.
+    #[serde(default)]
+    events: Vec<Value>,
+}
+
+struct Step {
+    until: Duration,
+    data: Option<Value>,
+    game_time: Option<f64>,
+}
+
+struct Timeline {
+    version: String,
+    steps: Vec<Step>,
+    started: Instant,
+    // Game seconds per real second, so tests can play a whole timeline quickly.
+    speed: f64,
+}
+
+fn default_version() -> String {
+    "15.22.719.4137".to_string()
+}
+
+struct Args {
+    timeline: PathBuf,
+    port: u16,
+    speed: f64,
+    cert: PathBuf,
+    key: PathBuf,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut timeline = None;
+    let mut port = 2999;
+    let mut speed = 1.0_f64;
+    let mut cert = PathBuf::from("tests/fixtures/tls/mock.crt");
+    let mut key = PathBuf::from("tests/fixtures/tls/mock.key");
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--port" => port = value()?.parse().map_err(|e| format!("--port: {}", e))?,
+            "--speed" => {
+                speed = value()?.parse().map_err(|e| format!("--speed: {}", e))?;
+                if speed.is_nan() || speed <= 0.0 {
+                    return Err("--speed must be positive".to_string());
+                }
+            }
+            "--cert" => cert = PathBuf::from(value()?),
+            "--key" => key = PathBuf::from(value()?),
+            _ if timeline.is_none() && !arg.starts_with("--") => timeline = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(Args { timeline: timeline.ok_or("a timeline file is required")?, port, speed, cert, key })
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn load_timeline(path: &Path, speed: f64) -> Result<Timeline, String> {
+    let file: TimelineFile = serde_json::from_value(read_json(path)?).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let dir = path.parent().unwrap_or(Path::new("."));
+
+    let mut until = Duration::ZERO;
+    let mut steps = Vec::with_capacity(file.steps.len());
+    for step in file.steps {
+        until += Duration::from_secs_f64(step.seconds.max(0.0) / speed);
+        let data = match &step.fixture {
+            Some(fixture) => {
+                let mut data = read_json(&dir.join(fixture))?;
+                if !step.events.is_empty() {
+                    let events = data.pointer_mut("/events/Events").and_then(Value::as_array_mut);
+                    match events {
+                        Some(events) => events.extend(step.events),
+                        None => data["events"] = json!({ "Events": step.events }),
+                    }
+                }
+                Some(data)
+            }
+            None => None,
+        };
+        steps.push(Step { until, data, game_time: step.game_time });
+    }
+
+    Ok(Timeline { version: file.version, steps, started: Instant::now(), speed })
+}
+
+fn tls_acceptor(cert: &Path, key: &Path) -> Result<TlsAcceptor, String> {
+    let open = |path: &Path| {
+        std::fs::File::open(path).map(BufReader::new).map_err(|e| format!("{}: {}", path.display(), e))
+    };
+
+    let certs: Vec<CertificateDer> = rustls_pemfile::certs(&mut open(cert)?)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("{}: {}", cert.display(), e))?;
+    let key: PrivateKeyDer = rustls_pemfile::private_key(&mut open(key)?)
+        .map_err(|e| format!("{}: {}", key.display(), e))?
+        .ok_or_else(|| format!("{}: no private key", key.display()))?;
+
+    let config = ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .and_then(|b| b.with_no_client_auth().with_single_cert(certs, key))
+        .map_err(|e| e.to_string())?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+impl Timeline {
+    fn end(&self) -> Duration {
+        self.steps.last().map_or(Duration::ZERO, |s| s.until)
+    }
+
+    fn respond(&self, path: &str) -> (u16, Value) {
+        let elapsed = self.started.elapsed();
+        let Some(index) = self.steps.iter().position(|s| elapsed < s.until) else {
+            return not_found(path);
+        };
+        let step = &self.steps[index];
+        let Some(data) = &step.data else {
+            return not_found(path);
+        };
+
+        match path {
+            "/liveclientdata/allgamedata" => {
+                let mut data = data.clone();
+                if let Some(start) = step.game_time {
+                    let step_start = index.checked_sub(1).map_or(Duration::ZERO, |i| self.steps[i].until);
+                    data["gameData"]["gameTime"] = json!(start + (elapsed - step_start).as_secs_f64() * self.speed);
+                }
+                (200, data)
+            }
+            "/liveclientdata/activeplayername" => (200, json!(active_player_name(&data["activePlayer"]))),
+            "/swagger/v3/openapi.json" => (200, json!({ "info": { "title": "Live Client Data API", "version": self.version } })),
+            _ => not_found(path),
+        }
+    }
+}
+
+// Riot ID when the fixture has one, else the legacy summoner name.
+fn active_player_name(player: &Value) -> String {
+    let text = |key: &str| player[key].as_str().filter(|s| !s.is_empty());
+    match (text("riotId"), text("riotIdGameName"), text("riotIdTagLine")) {
+        (Some(id), _, _) => id.to_string(),
+        (None, Some(name), Some(tag)) => format!("{}#{}", name, tag),
+        _ => text("summonerName").unwrap_or_default().to_string(),
+    }
+}
+
+// What the game client answers before the game has loaded.
+fn not_found(path: &str) -> (u16, Value) {
+    (404, json!({ "errorCode": "RESOURCE_NOT_FOUND", "httpStatus": 404, "message": format!("Unknown path {}", path) }))
+}
+
+async fn handle(stream: TcpStream, acceptor: TlsAcceptor, timeline: Arc<Timeline>) -> io::Result<()> {
+    let mut stream = acceptor.accept(stream).await?;
+
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 || head.len() > MAX_REQUEST_HEAD {
+            return Ok(());
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let path = head.split_whitespace().nth(1).unwrap_or("/");
+    let path = path.split('?').next().unwrap_or(path);
+    let (status, body) = timeline.respond(path);
+    let body = body.to_string();
+    let reason = if status == 200 { "OK" } else { "Not Found" };
+
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn run(args: Args) -> Result<(), String> {
+    let timeline = Arc::new(load_timeline(&args.timeline, args.speed)?);
+    let acceptor = tls_acceptor(&args.cert, &args.key)?;
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], args.port)))
+        .await
+        .map_err(|e| format!("port {}: {}", args.port, e))?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+
+    // Tests read this line to find the port when started with `--port 0`.
+    println!("listening on https://{}", addr);
+    io::stdout().flush().ok();
+
+    while let Some(remaining) = timeline.end().checked_sub(timeline.started.elapsed()) {
+        let Ok(accepted) = tokio::time::timeout(remaining, listener.accept()).await else {
+            break;
+        };
+        match accepted {
+            Ok((stream, _)) => {
+                let acceptor = acceptor.clone();
+                let timeline = Arc::clone(&timeline);
+                tokio::spawn(async move {
+                    if let Err(e) = handle(stream, acceptor, timeline).await {
+                        eprintln!("request failed: {}", e);
+                    }
+                });
+            }
+            Err(e) => eprintln!("accept failed: {}", e),
+        }
+    }
+
+    eprintln!("timeline finished");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use std::process::ExitCode;
+
+use aram_mayhem_advisor::data::{ChampionInfo, MayhemData};
+use aram_mayhem_advisor::validate::{validate, Severity};
+
+const DEFAULT_MAYHEM_PATH: &str = "data/mayhem.json";
+const DEFAULT_CHAMPIONS_PATH: &str = "data/champions.json";
+
+fn load<T: serde::de::DeserializeOwned>(path: &str) -> Result<T, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let quiet = args.iter().any(|a| a == "--errors-only");
+    let paths: Vec<&str> = args.iter().filter(|a| !a.starts_with("--")).map(String::as_str).collect();
+
+    let mayhem_path = paths.first().copied().unwrap_or(DEFAULT_MAYHEM_PATH);
+    let champions_path = paths.get(1).copied().unwrap_or(DEFAULT_CHAMPIONS_PATH);
+
+    let mayhem: MayhemData = match load(mayhem_path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let champions: HashMap<String, ChampionInfo> = match load(champions_path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = validate(&mayhem, &champions);
+
+    for issue in &report.issues {
+        if quiet && issue.severity == Severity::Warning {
+            continue;
+        }
+        println!("{}", issue);
+    }
+
+    println!("{} error(s), {} warning(s)", report.errors(), report.warnings());
+
+    if report.is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
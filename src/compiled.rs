@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::data::{AttackRange, Augment, Champion, ChampionClass, ChampionInfo, DamageType, Language, MayhemData, Name, Rarity, Synergy};
+use crate::metadata::DataMetadata;
+
+// Written by `build.rs` from the JSON sources in `data/`; the layout must match its encoder.
+pub const MAYHEM_BIN: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/mayhem.bin"));
+pub const CHAMPIONS_BIN: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/champions.bin"));
+
+include!("compiled_layout.rs");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    BadMagic,
+    UnexpectedEof,
+    InvalidUtf8,
+    InvalidString(u32),
+    InvalidVariant(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "not a compiled data file"),
+            DecodeError::UnexpectedEof => write!(f, "unexpected end of compiled data"),
+            DecodeError::InvalidUtf8 => write!(f, "invalid UTF-8 in string table"),
+            DecodeError::InvalidString(index) => write!(f, "string index {} out of range", index),
+            DecodeError::InvalidVariant(index) => write!(f, "variant index {} out of range", index),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    strings: Vec<Arc<str>>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        let Some(rest) = bytes.strip_prefix(MAGIC) else {
+            return Err(DecodeError::BadMagic);
+        };
+
+        let mut decoder = Self { bytes: rest, strings: Vec::new() };
+        let count = decoder.u32()? as usize;
+        decoder.strings.reserve(count);
+        for _ in 0..count {
+            let len = decoder.u32()? as usize;
+            let raw = decoder.take(len)?;
+            let s = std::str::from_utf8(raw).map_err(|_| DecodeError::InvalidUtf8)?;
+            decoder.strings.push(Arc::from(s));
+        }
+
+        Ok(decoder)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < len {
+            return Err(DecodeError::UnexpectedEof);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, DecodeError> {
+        Ok(i32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn opt_i32(&mut self) -> Result<Option<i32>, DecodeError> {
+        let present = self.u8()? != 0;
+        let value = self.i32()?;
+        Ok(present.then_some(value))
+    }
+
+    fn opt_i64(&mut self) -> Result<Option<i64>, DecodeError> {
+        let present = self.u8()? != 0;
+        let value = i64::from_le_bytes(self.take(8)?.try_into().unwrap());
+        Ok(present.then_some(value))
+    }
+
+    fn opt_f64(&mut self) -> Result<Option<f64>, DecodeError> {
+        let present = self.u8()? != 0;
+        let value = self.f64()?;
+        Ok(present.then_some(value))
+    }
+
+    fn opt_str(&mut self) -> Result<Option<Arc<str>>, DecodeError> {
+        let index = self.u32()?;
+        if index == NONE {
+            return Ok(None);
+        }
+        self.strings
+            .get(index as usize)
+            .cloned()
+            .map(Some)
+            .ok_or(DecodeError::InvalidString(index))
+    }
+
+    fn string(&mut self) -> Result<String, DecodeError> {
+        Ok(self.opt_str()?.as_deref().unwrap_or_default().to_string())
+    }
+
+    fn variant<T: Copy>(&mut self, all: &[T]) -> Result<Option<T>, DecodeError> {
+        match self.u8()? {
+            u8::MAX => Ok(None),
+            index => all.get(index as usize).copied().map(Some).ok_or(DecodeError::InvalidVariant(index)),
+        }
+    }
+
+    fn name(&mut self) -> Result<Name, DecodeError> {
+        let mut names = BTreeMap::new();
+        for language in Language::ALL {
+            if let Some(s) = self.opt_str()? {
+                names.insert(language, s);
+            }
+        }
+        Ok(Name(names))
+    }
+}
+
+pub fn decode_mayhem(bytes: &[u8]) -> Result<MayhemData, DecodeError> {
+    let mut d = Decoder::new(bytes)?;
+
+    let count = d.u32()? as usize;
+    let mut augments = Vec::with_capacity(count);
+    for _ in 0..count {
+        augments.push(Arc::new(Augment {
+            id: d.i32()?,
+            name: d.name()?,
+            tier: d.opt_str()?.map(|s| s.to_string()),
+            popularity: d.opt_str()?.map(|s| s.to_string()),
+            games: d.opt_i32()?,
+            win_rate: d.opt_f64()?,
+            rarity: d.variant(&Rarity::ALL)?,
+        }));
+    }
+
+    let count = d.u32()? as usize;
+    let mut champions = HashMap::with_capacity(count);
+    for _ in 0..count {
+        let key = d.string()?;
+        let entries = d.u32()? as usize;
+        let mut stats = Vec::with_capacity(entries);
+        for _ in 0..entries {
+            stats.push(Champion {
+                id: d.i32()?,
+                tier: d.string()?,
+                popularity: d.string()?,
+                games: d.i32()?,
+                win_rate: d.opt_f64()?,
+            });
+        }
+        champions.insert(key, stats);
+    }
+
+    let metadata = if d.u8()? != 0 {
+        Some(DataMetadata {
+            patch: d.opt_str()?.map(|s| s.to_string()),
+            collected_from: d.opt_str()?.map(|s| s.to_string()),
+            collected_to: d.opt_str()?.map(|s| s.to_string()),
+            total_games: d.opt_i64()?,
+            source: d.opt_str()?.map(|s| s.to_string()),
+        })
+    } else {
+        None
+    };
+
+    let count = d.u32()? as usize;
+    let mut synergies = Vec::with_capacity(count);
+    for _ in 0..count {
+        synergies.push(Synergy {
+            augments: [d.i32()?, d.i32()?],
+            tier: d.string()?,
+            games: d.i32()?,
+            win_rate: d.opt_f64()?,
+        });
+    }
+
+    Ok(MayhemData { metadata, champions, augments, synergies })
+}
+
+pub fn decode_champions(bytes: &[u8]) -> Result<HashMap<String, ChampionInfo>, DecodeError> {
+    let mut d = Decoder::new(bytes)?;
+
+    let count = d.u32()? as usize;
+    let mut champions = HashMap::with_capacity(count);
+    for _ in 0..count {
+        let id = d.string()?;
+        let name = d.name()?;
+        let key = d.opt_i32()?;
+        let count = d.u8()? as usize;
+        let mut classes = Vec::with_capacity(count);
+        for _ in 0..count {
+            classes.extend(d.variant(&ChampionClass::ALL)?);
+        }
+        champions.insert(id, ChampionInfo {
+            name,
+            key,
+            classes,
+            damage_type: d.variant(&DamageType::ALL)?,
+            range: d.variant(&AttackRange::ALL)?,
+        });
+    }
+
+    Ok(champions)
+}
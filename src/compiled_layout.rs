@@ -0,0 +1,19 @@
+// Layout constants shared by `build.rs` (encoder) and `src/compiled.rs` (decoder) through `include!`;
+// not a module of its own.
+
+pub const MAGIC: &[u8; 4] = b"AMD1";
+// String index of an absent string.
+pub const NONE: u32 = u32::MAX;
+
+// Order of `Language::ALL`.
+pub const LANGUAGE_KEYS: [&str; 27] = [
+    "ar_ae", "cs_cz", "de_de", "el_gr", "en_au", "en_gb", "en_ph", "en_sg", "es_ar",
+    "es_es", "es_mx", "fr_fr", "hu_hu", "id_id", "it_it", "ja_jp", "ko_kr", "pl_pl",
+    "pt_br", "ro_ro", "ru_ru", "th_th", "tr_tr", "vi_vn", "zh_cn", "zh_my", "zh_tw",
+];
+// Variant order of `ChampionClass::ALL`, `DamageType::ALL` and `AttackRange::ALL`.
+pub const CLASS_KEYS: [&str; 6] = ["tank", "fighter", "mage", "assassin", "marksman", "support"];
+pub const DAMAGE_KEYS: [&str; 3] = ["physical", "magic", "mixed"];
+pub const RANGE_KEYS: [&str; 2] = ["melee", "ranged"];
+// Variant order of `Rarity::ALL`.
+pub const RARITY_KEYS: [&str; 3] = ["silver", "gold", "prismatic"];
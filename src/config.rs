@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::live_client::DEFAULT_BASE_URL;
+use crate::recommend::Weights;
+use crate::timing::AUGMENT_LEVELS;
+use crate::tls::{TlsError, TlsMode};
+
+const APP_DIR: &str = "aram-mayhem-advisor";
+pub const CONFIG_FILE: &str = "config.json";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    // Warn when the data's collection window ended more than this many days ago.
+    pub max_data_age_days: i64,
+    pub weights: Weights,
+    // Champion levels that open an augment window; OCR only runs inside one.
+    pub augment_levels: Vec<i32>,
+    // Live Client Data API address; point it at `mock-live-client` to replay a game.
+    pub live_client_url: String,
+    // PEM root to trust instead of Riot's, e.g. `tests/fixtures/tls/mock-ca.crt` for the mock server.
+    pub live_client_ca: Option<PathBuf>,
+    // Skips certificate checks entirely; anything listening on the port is trusted.
+    pub insecure_live_client: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            max_data_age_days: 30,
+            weights: Weights::default(),
+            augment_levels: AUGMENT_LEVELS.to_vec(),
+            live_client_url: DEFAULT_BASE_URL.to_string(),
+            live_client_ca: None,
+            insecure_live_client: false,
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn load() -> Self {
+        let Some(path) = config_file(CONFIG_FILE) else {
+            return Self::default();
+        };
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        serde_json::from_str(&text).unwrap_or_else(|e| {
+            eprintln!("Failed to parse {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn tls_mode(&self) -> Result<TlsMode, TlsError> {
+        if self.insecure_live_client {
+            return Ok(TlsMode::Insecure);
+        }
+        match &self.live_client_ca {
+            Some(path) => TlsMode::pinned_file(path.clone()),
+            None => Ok(TlsMode::RiotRoot),
+        }
+    }
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("APPDATA")
+        .or_else(|| std::env::var_os("XDG_CONFIG_HOME"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(base.join(APP_DIR))
+}
+
+pub fn config_file(name: &str) -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(name))
+}
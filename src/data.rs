@@ -1,8 +1,15 @@
-use std::sync::LazyLock;
-use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+use serde::{de, Serialize, Serializer, Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
 use strsim::jaro_winkler;
 
+use crate::compiled;
+use crate::config::config_file;
+use crate::metadata::DataMetadata;
+use crate::store::{DataStore, OVERRIDE_FILE};
+
 pub fn extract_title_from_ocr(items: &[(&str, i32, i32)], y_threshold: i32) -> String {
     if items.is_empty() {
         return String::new();
@@ -25,7 +32,7 @@ pub fn extract_title_from_ocr(items: &[(&str, i32, i32)], y_threshold: i32) -> S
 }
 
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Language {
     ArAe,
     CsCz,
@@ -56,10 +63,180 @@ pub enum Language {
     ZhTw,
 }
 
+struct Locale {
+    code: &'static str,
+    key: &'static str,
+    fallback: Option<Language>,
+}
+
+const DEFAULT_FALLBACK: Language = Language::EnGb;
+
+impl Language {
+    pub const ALL: [Language; 27] = [
+        Language::ArAe,
+        Language::CsCz,
+        Language::DeDe,
+        Language::ElGr,
+        Language::EnAu,
+        Language::EnGb,
+        Language::EnPh,
+        Language::EnSg,
+        Language::EsAr,
+        Language::EsEs,
+        Language::EsMx,
+        Language::FrFr,
+        Language::HuHu,
+        Language::IdId,
+        Language::ItIt,
+        Language::JaJp,
+        Language::KoKr,
+        Language::PlPl,
+        Language::PtBr,
+        Language::RoRo,
+        Language::RuRu,
+        Language::ThTh,
+        Language::TrTr,
+        Language::ViVn,
+        Language::ZhCn,
+        Language::ZhMy,
+        Language::ZhTw,
+    ];
+
+    fn locale(self) -> &'static Locale {
+        match self {
+            Language::ArAe => &Locale { code: "ar_AE", key: "ar_ae", fallback: None },
+            Language::CsCz => &Locale { code: "cs_CZ", key: "cs_cz", fallback: None },
+            Language::DeDe => &Locale { code: "de_DE", key: "de_de", fallback: None },
+            Language::ElGr => &Locale { code: "el_GR", key: "el_gr", fallback: None },
+            Language::EnAu => &Locale { code: "en_AU", key: "en_au", fallback: Some(Language::EnGb) },
+            Language::EnGb => &Locale { code: "en_GB", key: "en_gb", fallback: None },
+            Language::EnPh => &Locale { code: "en_PH", key: "en_ph", fallback: Some(Language::EnGb) },
+            Language::EnSg => &Locale { code: "en_SG", key: "en_sg", fallback: Some(Language::EnGb) },
+            Language::EsAr => &Locale { code: "es_AR", key: "es_ar", fallback: Some(Language::EsMx) },
+            Language::EsEs => &Locale { code: "es_ES", key: "es_es", fallback: None },
+            Language::EsMx => &Locale { code: "es_MX", key: "es_mx", fallback: Some(Language::EsEs) },
+            Language::FrFr => &Locale { code: "fr_FR", key: "fr_fr", fallback: None },
+            Language::HuHu => &Locale { code: "hu_HU", key: "hu_hu", fallback: None },
+            Language::IdId => &Locale { code: "id_ID", key: "id_id", fallback: None },
+            Language::ItIt => &Locale { code: "it_IT", key: "it_it", fallback: None },
+            Language::JaJp => &Locale { code: "ja_JP", key: "ja_jp", fallback: None },
+            Language::KoKr => &Locale { code: "ko_KR", key: "ko_kr", fallback: None },
+            Language::PlPl => &Locale { code: "pl_PL", key: "pl_pl", fallback: None },
+            Language::PtBr => &Locale { code: "pt_BR", key: "pt_br", fallback: None },
+            Language::RoRo => &Locale { code: "ro_RO", key: "ro_ro", fallback: None },
+            Language::RuRu => &Locale { code: "ru_RU", key: "ru_ru", fallback: None },
+            Language::ThTh => &Locale { code: "th_TH", key: "th_th", fallback: None },
+            Language::TrTr => &Locale { code: "tr_TR", key: "tr_tr", fallback: None },
+            Language::ViVn => &Locale { code: "vi_VN", key: "vi_vn", fallback: None },
+            Language::ZhCn => &Locale { code: "zh_CN", key: "zh_cn", fallback: None },
+            Language::ZhMy => &Locale { code: "zh_MY", key: "zh_my", fallback: Some(Language::ZhCn) },
+            Language::ZhTw => &Locale { code: "zh_TW", key: "zh_tw", fallback: Some(Language::ZhCn) },
+        }
+    }
+
+    /// Riot locale code, e.g. `ko_KR`.
+    pub fn code(self) -> &'static str {
+        self.locale().code
+    }
+
+    /// Lowercase key used in the data files, e.g. `ko_kr`.
+    pub fn key(self) -> &'static str {
+        self.locale().key
+    }
+
+    pub fn fallback(self) -> Option<Language> {
+        self.locale().fallback
+    }
+
+    /// The language itself, its fallbacks in order, then `en_GB` as a last resort.
+    pub fn fallback_chain(self) -> FallbackChain {
+        FallbackChain { next: Some(self), seen: 0, done: false }
+    }
+}
+
+/// Iterator returned by `Language::fallback_chain`; yields each language once.
+pub struct FallbackChain {
+    next: Option<Language>,
+    // Bit per `Language as u32`, so a cycle in the table ends the chain.
+    seen: u32,
+    done: bool,
+}
+
+impl Iterator for FallbackChain {
+    type Item = Language;
+
+    fn next(&mut self) -> Option<Language> {
+        if let Some(lang) = self.next.take().filter(|l| self.seen & (1 << *l as u32) == 0) {
+            self.seen |= 1 << lang as u32;
+            self.next = lang.fallback();
+            return Some(lang);
+        }
+        if self.done {
+            return None;
+        }
+        self.done = true;
+        (self.seen & (1 << DEFAULT_FALLBACK as u32) == 0).then_some(DEFAULT_FALLBACK)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLanguageError(String);
+
+impl fmt::Display for ParseLanguageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown locale: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseLanguageError {}
+
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = s.trim().replace('-', "_").to_lowercase();
+        Language::ALL
+            .into_iter()
+            .find(|lang| lang.key() == key)
+            .ok_or_else(|| ParseLanguageError(s.to_string()))
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for Language {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+pub const TIERS: [&str; 6] = ["S+", "S", "A", "B", "C", "D"];
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MayhemData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<DataMetadata>,
     pub champions: HashMap<String, Vec<Champion>>,
-    pub augments: Vec<Augment>,
+    pub augments: Vec<Arc<Augment>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub synergies: Vec<Synergy>,
+}
+
+impl MayhemData {
+    pub fn synergy(&self, a: i32, b: i32) -> Option<&Synergy> {
+        self.synergies.iter().find(|s| s.pairs(a, b))
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -72,99 +249,171 @@ pub struct Augment {
     pub popularity: Option<String>,
     #[serde(default)]
     pub games: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub win_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rarity: Option<Rarity>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rarity {
+    Silver,
+    Gold,
+    Prismatic,
+}
+
+impl Rarity {
+    // Indexed by the compiled data; append only.
+    pub const ALL: [Rarity; 3] = [Rarity::Silver, Rarity::Gold, Rarity::Prismatic];
 }
 
+// Stats for games where both augments were picked.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Name {
-    pub ar_ae: String,
-    pub cs_cz: String,
-    pub de_de: String,
-    pub el_gr: String,
-    pub en_au: String,
-    pub en_gb: String,
-    pub en_ph: String,
-    pub en_sg: String,
-    pub es_ar: String,
-    pub es_es: String,
-    pub es_mx: String,
-    pub fr_fr: String,
-    pub hu_hu: String,
-    pub id_id: String,
-    pub it_it: String,
-    pub ja_jp: String,
-    pub ko_kr: String,
-    pub pl_pl: String,
-    pub pt_br: String,
-    pub ro_ro: String,
-    pub ru_ru: String,
-    pub th_th: String,
-    pub tr_tr: String,
-    pub vi_vn: String,
-    pub zh_cn: String,
-    pub zh_my: String,
-    pub zh_tw: String,
+pub struct Synergy {
+    pub augments: [i32; 2],
+    pub tier: String,
+    pub games: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub win_rate: Option<f64>,
+}
+
+impl Synergy {
+    pub fn pairs(&self, a: i32, b: i32) -> bool {
+        self.augments == [a, b] || self.augments == [b, a]
+    }
+
+    pub fn partner(&self, id: i32) -> Option<i32> {
+        match self.augments {
+            [a, b] if a == id => Some(b),
+            [a, b] if b == id => Some(a),
+            _ => None,
+        }
+    }
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Name(pub BTreeMap<Language, Arc<str>>);
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Champion {
     pub id: i32,
     pub tier: String,
     pub popularity: String,
     pub games: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub win_rate: Option<f64>,
 }
 
-const MAYHEM_JSON: &str = include_str!("../data/mayhem.json");
-const CHAMPIONS_JSON: &str = include_str!("../data/champions.json");
-
-pub static MAYHEM_DATA: LazyLock<MayhemData> = LazyLock::new(|| {
-    serde_json::from_str(MAYHEM_JSON).unwrap()
+pub static MAYHEM_DATA: LazyLock<Arc<DataStore>> = LazyLock::new(|| {
+    let embedded = compiled::decode_mayhem(compiled::MAYHEM_BIN).expect("Failed to decode mayhem data");
+    Arc::new(DataStore::new(embedded, config_file(OVERRIDE_FILE)))
 });
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChampionClass {
+    Tank,
+    Fighter,
+    Mage,
+    Assassin,
+    Marksman,
+    Support,
+}
+
+impl ChampionClass {
+    // Indexed by the compiled data; append only.
+    pub const ALL: [ChampionClass; 6] = [
+        ChampionClass::Tank,
+        ChampionClass::Fighter,
+        ChampionClass::Mage,
+        ChampionClass::Assassin,
+        ChampionClass::Marksman,
+        ChampionClass::Support,
+    ];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DamageType {
+    Physical,
+    Magic,
+    Mixed,
+}
+
+impl DamageType {
+    pub const ALL: [DamageType; 3] = [DamageType::Physical, DamageType::Magic, DamageType::Mixed];
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttackRange {
+    Melee,
+    Ranged,
+}
+
+impl AttackRange {
+    pub const ALL: [AttackRange; 2] = [AttackRange::Melee, AttackRange::Ranged];
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChampionInfo {
     pub name: Name,
+    // Riot's numeric champion id; variants such as `ruby_*` have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<i32>,
+    // Primary class first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<ChampionClass>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub damage_type: Option<DamageType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<AttackRange>,
+}
+
+impl ChampionInfo {
+    pub fn primary_class(&self) -> Option<ChampionClass> {
+        self.classes.first().copied()
+    }
+
+    pub fn has_class(&self, class: ChampionClass) -> bool {
+        self.classes.contains(&class)
+    }
 }
 
+// The entry `champions.json` keeps for "no champion" (e.g. while spectating); it never has stats.
+pub const NO_CHAMPION: &str = "none";
+
 pub static CHAMPIONS_DATA: LazyLock<HashMap<String, ChampionInfo>> = LazyLock::new(|| {
-    serde_json::from_str(CHAMPIONS_JSON).unwrap()
+    compiled::decode_champions(compiled::CHAMPIONS_BIN).expect("Failed to decode champion data")
 });
 
+pub fn parse_percent(text: &str) -> Option<f64> {
+    text.trim().strip_suffix('%')?.trim().parse().ok()
+}
+
 pub fn get_champion_name(champion_id: &str, lang: Language) -> Option<String> {
     CHAMPIONS_DATA.get(&champion_id.to_lowercase())
         .map(|info| info.name.get(lang).to_string())
 }
 
+pub fn get_champion_info(champion_id: &str) -> Option<&'static ChampionInfo> {
+    CHAMPIONS_DATA.get(&champion_id.to_lowercase())
+}
+
 impl Name {
+    /// Translation for `lang`, falling back along its chain when missing or empty.
     pub fn get(&self, lang: Language) -> &str {
-        match lang {
-            Language::ArAe => &self.ar_ae,
-            Language::CsCz => &self.cs_cz,
-            Language::DeDe => &self.de_de,
-            Language::ElGr => &self.el_gr,
-            Language::EnAu => &self.en_au,
-            Language::EnGb => &self.en_gb,
-            Language::EnPh => &self.en_ph,
-            Language::EnSg => &self.en_sg,
-            Language::EsAr => &self.es_ar,
-            Language::EsEs => &self.es_es,
-            Language::EsMx => &self.es_mx,
-            Language::FrFr => &self.fr_fr,
-            Language::HuHu => &self.hu_hu,
-            Language::IdId => &self.id_id,
-            Language::ItIt => &self.it_it,
-            Language::JaJp => &self.ja_jp,
-            Language::KoKr => &self.ko_kr,
-            Language::PlPl => &self.pl_pl,
-            Language::PtBr => &self.pt_br,
-            Language::RoRo => &self.ro_ro,
-            Language::RuRu => &self.ru_ru,
-            Language::ThTh => &self.th_th,
-            Language::TrTr => &self.tr_tr,
-            Language::ViVn => &self.vi_vn,
-            Language::ZhCn => &self.zh_cn,
-            Language::ZhMy => &self.zh_my,
-            Language::ZhTw => &self.zh_tw,
-        }
+        lang.fallback_chain()
+            .find_map(|l| self.get_exact(l))
+            .unwrap_or("")
+    }
+
+    pub fn get_exact(&self, lang: Language) -> Option<&str> {
+        self.0.get(&lang)
+            .map(|name| &**name)
+            .filter(|name| !name.trim().is_empty())
     }
 }
 
@@ -194,12 +443,12 @@ fn calculate_similarity(ocr_text: &str, augment_name: &str) -> f64 {
 
 #[derive(Debug, Clone)]
 pub struct AugmentMatch {
-    pub augment: Augment,
+    pub augment: Arc<Augment>,
     pub similarity: f64,
 }
 
 pub fn find_augment(ocr_text: &str, lang: Language, threshold: f64) -> Option<AugmentMatch> {
-    let data = &*MAYHEM_DATA;
+    let data = MAYHEM_DATA.get();
     let normalized_ocr = normalize_text(ocr_text);
 
     for augment in &data.augments {
@@ -208,7 +457,7 @@ pub fn find_augment(ocr_text: &str, lang: Language, threshold: f64) -> Option<Au
 
         if normalized_ocr == normalized_name {
             return Some(AugmentMatch {
-                augment: augment.clone(),
+                augment: Arc::clone(augment),
                 similarity: 1.0,
             });
         }
@@ -220,13 +469,11 @@ pub fn find_augment(ocr_text: &str, lang: Language, threshold: f64) -> Option<Au
         let name = augment.name.get(lang);
         let similarity = calculate_similarity(ocr_text, name);
 
-        if similarity >= threshold {
-            if best_match.as_ref().map_or(true, |m| similarity > m.similarity) {
-                best_match = Some(AugmentMatch {
-                    augment: augment.clone(),
-                    similarity,
-                });
-            }
+        if similarity >= threshold && best_match.as_ref().is_none_or(|m| similarity > m.similarity) {
+            best_match = Some(AugmentMatch {
+                augment: Arc::clone(augment),
+                similarity,
+            });
         }
     }
 
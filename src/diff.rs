@@ -0,0 +1,356 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::data::{parse_percent, Augment, Champion, Language, MayhemData};
+
+#[derive(Clone, Debug)]
+pub struct DiffOptions {
+    pub language: Language,
+    pub popularity_threshold: f64,
+    pub games_ratio_threshold: f64,
+    pub min_games: i32,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            language: Language::KoKr,
+            popularity_threshold: 0.5,
+            games_ratio_threshold: 0.5,
+            min_games: 100,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AugmentRef {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TierChange {
+    pub champion: Option<String>,
+    pub augment: AugmentRef,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StatSwing {
+    pub champion: Option<String>,
+    pub augment: AugmentRef,
+    pub old_popularity: Option<String>,
+    pub new_popularity: Option<String>,
+    pub old_games: i32,
+    pub new_games: i32,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct DataDiff {
+    pub augments_added: Vec<AugmentRef>,
+    pub augments_removed: Vec<AugmentRef>,
+    pub champions_added: Vec<String>,
+    pub champions_removed: Vec<String>,
+    pub tier_changes: Vec<TierChange>,
+    pub champion_tier_changes: Vec<TierChange>,
+    pub swings: Vec<StatSwing>,
+}
+
+impl DataDiff {
+    pub fn is_empty(&self) -> bool {
+        self.augments_added.is_empty()
+            && self.augments_removed.is_empty()
+            && self.champions_added.is_empty()
+            && self.champions_removed.is_empty()
+            && self.tier_changes.is_empty()
+            && self.champion_tier_changes.is_empty()
+            && self.swings.is_empty()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffFormat {
+    Text,
+    Json,
+    Markdown,
+}
+
+impl std::str::FromStr for DiffFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" | "txt" => Ok(DiffFormat::Text),
+            "json" => Ok(DiffFormat::Json),
+            "markdown" | "md" => Ok(DiffFormat::Markdown),
+            _ => Err(format!("unknown format: {}", s)),
+        }
+    }
+}
+
+pub fn diff(old: &MayhemData, new: &MayhemData, options: &DiffOptions) -> DataDiff {
+    let old_augments: HashMap<i32, &Augment> = old.augments.iter().map(|a| (a.id, a.as_ref())).collect();
+    let new_augments: HashMap<i32, &Augment> = new.augments.iter().map(|a| (a.id, a.as_ref())).collect();
+
+    let augment_ref = |id: i32| AugmentRef {
+        id,
+        name: new_augments
+            .get(&id)
+            .or_else(|| old_augments.get(&id))
+            .map(|a| a.name.get(options.language).to_string())
+            .unwrap_or_default(),
+    };
+
+    let mut result = DataDiff::default();
+
+    let ids: BTreeSet<i32> = old_augments.keys().chain(new_augments.keys()).copied().collect();
+    for id in ids {
+        match (old_augments.get(&id), new_augments.get(&id)) {
+            (None, Some(_)) => result.augments_added.push(augment_ref(id)),
+            (Some(_), None) => result.augments_removed.push(augment_ref(id)),
+            (Some(o), Some(n)) => {
+                if o.tier != n.tier {
+                    result.tier_changes.push(TierChange {
+                        champion: None,
+                        augment: augment_ref(id),
+                        old: o.tier.clone(),
+                        new: n.tier.clone(),
+                    });
+                }
+
+                let swing = StatSwing {
+                    champion: None,
+                    augment: augment_ref(id),
+                    old_popularity: o.popularity.clone(),
+                    new_popularity: n.popularity.clone(),
+                    old_games: o.games.unwrap_or(0),
+                    new_games: n.games.unwrap_or(0),
+                };
+                if is_swing(&swing, options) {
+                    result.swings.push(swing);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    let champions: BTreeSet<&String> = old.champions.keys().chain(new.champions.keys()).collect();
+    for champion in champions {
+        let (old_entries, new_entries) = match (old.champions.get(champion), new.champions.get(champion)) {
+            (None, Some(_)) => {
+                result.champions_added.push(champion.clone());
+                continue;
+            }
+            (Some(_), None) => {
+                result.champions_removed.push(champion.clone());
+                continue;
+            }
+            (Some(o), Some(n)) => (o, n),
+            (None, None) => continue,
+        };
+
+        let old_by_id: HashMap<i32, &Champion> = old_entries.iter().map(|c| (c.id, c)).collect();
+        let new_by_id: HashMap<i32, &Champion> = new_entries.iter().map(|c| (c.id, c)).collect();
+        let ids: BTreeSet<i32> = old_by_id.keys().chain(new_by_id.keys()).copied().collect();
+
+        for id in ids {
+            let o = old_by_id.get(&id);
+            let n = new_by_id.get(&id);
+
+            let old_tier = o.map(|c| c.tier.clone());
+            let new_tier = n.map(|c| c.tier.clone());
+            if old_tier != new_tier {
+                result.champion_tier_changes.push(TierChange {
+                    champion: Some(champion.clone()),
+                    augment: augment_ref(id),
+                    old: old_tier,
+                    new: new_tier,
+                });
+            }
+
+            if let (Some(o), Some(n)) = (o, n) {
+                let swing = StatSwing {
+                    champion: Some(champion.clone()),
+                    augment: augment_ref(id),
+                    old_popularity: Some(o.popularity.clone()),
+                    new_popularity: Some(n.popularity.clone()),
+                    old_games: o.games,
+                    new_games: n.games,
+                };
+                if is_swing(&swing, options) {
+                    result.swings.push(swing);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn is_swing(swing: &StatSwing, options: &DiffOptions) -> bool {
+    if swing.old_games.max(swing.new_games) < options.min_games {
+        return false;
+    }
+
+    let old_pop = swing.old_popularity.as_deref().and_then(parse_percent);
+    let new_pop = swing.new_popularity.as_deref().and_then(parse_percent);
+    let popularity_moved = match (old_pop, new_pop) {
+        (Some(o), Some(n)) => (n - o).abs() >= options.popularity_threshold,
+        _ => false,
+    };
+
+    let old_games = swing.old_games.max(1) as f64;
+    let games_moved = ((swing.new_games as f64 - old_games) / old_games).abs() >= options.games_ratio_threshold;
+
+    popularity_moved || games_moved
+}
+
+fn or_dash(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("-")
+}
+
+fn scope(champion: &Option<String>) -> String {
+    match champion {
+        Some(champion) => format!("{} / ", champion),
+        None => String::new(),
+    }
+}
+
+// Keeps a value inside its Markdown table cell.
+fn cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+pub fn render(diff: &DataDiff, format: DiffFormat) -> String {
+    match format {
+        DiffFormat::Text => render_text(diff),
+        DiffFormat::Json => serde_json::to_string_pretty(diff).unwrap_or_default(),
+        DiffFormat::Markdown => render_markdown(diff),
+    }
+}
+
+fn render_text(diff: &DataDiff) -> String {
+    let mut out = String::new();
+
+    if diff.is_empty() {
+        out.push_str("no changes\n");
+        return out;
+    }
+
+    for a in &diff.augments_added {
+        let _ = writeln!(out, "+ augment {} {}", a.id, a.name);
+    }
+    for a in &diff.augments_removed {
+        let _ = writeln!(out, "- augment {} {}", a.id, a.name);
+    }
+    for c in &diff.champions_added {
+        let _ = writeln!(out, "+ champion {}", c);
+    }
+    for c in &diff.champions_removed {
+        let _ = writeln!(out, "- champion {}", c);
+    }
+    for change in diff.tier_changes.iter().chain(&diff.champion_tier_changes) {
+        let _ = writeln!(
+            out,
+            "~ {}{} ({}): {} -> {}",
+            scope(&change.champion),
+            change.augment.name,
+            change.augment.id,
+            or_dash(&change.old),
+            or_dash(&change.new)
+        );
+    }
+    for swing in &diff.swings {
+        let _ = writeln!(
+            out,
+            "* {}{} ({}): popularity {} -> {}, games {} -> {}",
+            scope(&swing.champion),
+            swing.augment.name,
+            swing.augment.id,
+            or_dash(&swing.old_popularity),
+            or_dash(&swing.new_popularity),
+            swing.old_games,
+            swing.new_games
+        );
+    }
+
+    out
+}
+
+fn render_markdown(diff: &DataDiff) -> String {
+    let mut out = String::from("## Data changes\n\n");
+
+    if diff.is_empty() {
+        out.push_str("No changes.\n");
+        return out;
+    }
+
+    if !diff.augments_added.is_empty() || !diff.augments_removed.is_empty() {
+        out.push_str("### Augments\n\n");
+        for a in &diff.augments_added {
+            let _ = writeln!(out, "- Added **{}** ({})", a.name, a.id);
+        }
+        for a in &diff.augments_removed {
+            let _ = writeln!(out, "- Removed **{}** ({})", a.name, a.id);
+        }
+        out.push('\n');
+    }
+
+    if !diff.champions_added.is_empty() || !diff.champions_removed.is_empty() {
+        out.push_str("### Champions\n\n");
+        for c in &diff.champions_added {
+            let _ = writeln!(out, "- Added `{}`", c);
+        }
+        for c in &diff.champions_removed {
+            let _ = writeln!(out, "- Removed `{}`", c);
+        }
+        out.push('\n');
+    }
+
+    for (title, changes) in [
+        ("Global tier changes", &diff.tier_changes),
+        ("Champion tier changes", &diff.champion_tier_changes),
+    ] {
+        if changes.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "### {}\n", title);
+        out.push_str("| Champion | Augment | Old | New |\n|---|---|---|---|\n");
+        for change in changes {
+            let _ = writeln!(
+                out,
+                "| {} | {} ({}) | {} | {} |",
+                cell(change.champion.as_deref().unwrap_or("-")),
+                cell(&change.augment.name),
+                change.augment.id,
+                cell(or_dash(&change.old)),
+                cell(or_dash(&change.new))
+            );
+        }
+        out.push('\n');
+    }
+
+    if !diff.swings.is_empty() {
+        out.push_str("### Popularity and games swings\n\n");
+        out.push_str("| Champion | Augment | Popularity | Games |\n|---|---|---|---|\n");
+        for swing in &diff.swings {
+            let _ = writeln!(
+                out,
+                "| {} | {} ({}) | {} → {} | {} → {} |",
+                cell(swing.champion.as_deref().unwrap_or("-")),
+                cell(&swing.augment.name),
+                swing.augment.id,
+                cell(or_dash(&swing.old_popularity)),
+                cell(or_dash(&swing.new_popularity)),
+                swing.old_games,
+                swing.new_games
+            );
+        }
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,350 @@
+use serde::Serialize;
+
+use crate::data::{ChampionClass, Language, MayhemData, Name, Rarity};
+
+/// Languages with their own explanation table; the rest read the first of these on their fallback chain.
+pub const EXPLANATION_LANGUAGES: [Language; 21] = [
+    Language::ArAe,
+    Language::CsCz,
+    Language::DeDe,
+    Language::ElGr,
+    Language::EnGb,
+    Language::EsEs,
+    Language::FrFr,
+    Language::HuHu,
+    Language::IdId,
+    Language::ItIt,
+    Language::JaJp,
+    Language::KoKr,
+    Language::PlPl,
+    Language::PtBr,
+    Language::RoRo,
+    Language::RuRu,
+    Language::ThTh,
+    Language::TrTr,
+    Language::ViVn,
+    Language::ZhCn,
+    Language::ZhTw,
+];
+
+// Templates fill `{tier}`, `{games}`, `{class}`, `{name}` and `{rarity}`. Classes and rarities are in enum order.
+struct Table {
+    champion_tier: &'static str,
+    class_tier: &'static str,
+    global_tier: &'static str,
+    low_sample: &'static str,
+    no_champion_data: &'static str,
+    synergy: &'static str,
+    rarity: &'static str,
+    classes: [&'static str; 6],
+    rarities: [&'static str; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Explanation {
+    ChampionTier { tier: String, games: i32 },
+    ClassTier { class: ChampionClass, tier: String, games: i32 },
+    GlobalTier { tier: String },
+    LowSample { games: i32 },
+    NoChampionData,
+    Synergy { partner: i32, tier: String },
+    TeamComp { rule: String, reason: Name },
+    Rule { rule: String, reason: Name },
+    Rarity(Rarity),
+}
+
+impl Explanation {
+    pub fn text(&self, lang: Language, data: &MayhemData) -> String {
+        let t = table(explanation_language(lang));
+
+        match self {
+            Explanation::ChampionTier { tier, games } => fill(t.champion_tier, &[("tier", tier), ("games", &group_thousands(*games))]),
+            Explanation::ClassTier { class, tier, games } => fill(
+                t.class_tier,
+                &[("tier", tier), ("games", &group_thousands(*games)), ("class", t.classes[*class as usize])],
+            ),
+            Explanation::GlobalTier { tier } => fill(t.global_tier, &[("tier", tier)]),
+            Explanation::LowSample { games } => fill(t.low_sample, &[("games", &group_thousands(*games))]),
+            Explanation::NoChampionData => t.no_champion_data.to_string(),
+            Explanation::Synergy { partner, tier } => {
+                let name = data
+                    .augments
+                    .iter()
+                    .find(|a| a.id == *partner)
+                    .map(|a| a.name.get(lang).to_string())
+                    .unwrap_or_else(|| partner.to_string());
+                fill(t.synergy, &[("tier", tier), ("name", &name)])
+            }
+            Explanation::TeamComp { rule, reason } | Explanation::Rule { rule, reason } => match reason.get(lang) {
+                "" => rule.clone(),
+                text => text.to_string(),
+            },
+            Explanation::Rarity(rarity) => fill(t.rarity, &[("rarity", t.rarities[*rarity as usize])]),
+        }
+    }
+}
+
+fn explanation_language(lang: Language) -> Language {
+    lang.fallback_chain()
+        .find(|l| EXPLANATION_LANGUAGES.contains(l))
+        .unwrap_or(Language::EnGb)
+}
+
+// The name goes last so a `{...}` inside an augment name is left alone.
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |text, (key, value)| text.replace(&format!("{{{}}}", key), value))
+}
+
+fn table(lang: Language) -> &'static Table {
+    match lang {
+        Language::ArAe => &Table {
+            champion_tier: "المستوى {tier} مع هذا البطل (المباريات: {games})",
+            class_tier: "المستوى {tier} لفئة {class} (المباريات: {games})",
+            global_tier: "المستوى {tier} إجمالًا",
+            low_sample: "عينة صغيرة (المباريات: {games})، الاعتماد على المستوى العام",
+            no_champion_data: "لا توجد بيانات للبطل، استخدام المستوى العام",
+            synergy: "تآزر مع اختيارك السابق {name} ({tier})",
+            rarity: "تعزيز {rarity}",
+            classes: ["الدبابة", "المقاتل", "الساحر", "القاتل", "الرامي", "الداعم"],
+            rarities: ["فضي", "ذهبي", "منشوري"],
+        },
+        Language::CsCz => &Table {
+            champion_tier: "Tier {tier} na tomto šampionovi (hry: {games})",
+            class_tier: "Tier {tier} pro třídu {class} (hry: {games})",
+            global_tier: "Celkový tier {tier}",
+            low_sample: "Málo her ({games}), hlavně celkový tier",
+            no_champion_data: "Žádná data šampiona, použit celkový tier",
+            synergy: "Synergie s dřívější volbou {name} ({tier})",
+            rarity: "{rarity} vylepšení",
+            classes: ["tank", "bojovník", "mág", "zabiják", "střelec", "podpora"],
+            rarities: ["Stříbrné", "Zlaté", "Prizmatické"],
+        },
+        Language::DeDe => &Table {
+            champion_tier: "Stufe {tier} mit diesem Champion über {games} Spiele",
+            class_tier: "Stufe {tier} für {class} über {games} Spiele",
+            global_tier: "Stufe {tier} insgesamt",
+            low_sample: "Wenige Spiele ({games}), vor allem Gesamtstufe",
+            no_champion_data: "Keine Championdaten, Gesamtstufe verwendet",
+            synergy: "Synergie mit deiner früheren Wahl {name} ({tier})",
+            rarity: "{rarity}-Augment",
+            classes: ["Tanks", "Kämpfer", "Magier", "Assassinen", "Schützen", "Unterstützer"],
+            rarities: ["Silber", "Gold", "Prisma"],
+        },
+        Language::ElGr => &Table {
+            champion_tier: "Βαθμίδα {tier} με αυτόν τον πρωταθλητή (παιχνίδια: {games})",
+            class_tier: "Βαθμίδα {tier} για την κατηγορία {class} (παιχνίδια: {games})",
+            global_tier: "Συνολική βαθμίδα {tier}",
+            low_sample: "Λίγα παιχνίδια ({games}), κυρίως η συνολική βαθμίδα",
+            no_champion_data: "Χωρίς δεδομένα πρωταθλητή, χρήση συνολικής βαθμίδας",
+            synergy: "Συνέργεια με την προηγούμενη επιλογή σου {name} ({tier})",
+            rarity: "{rarity} ενίσχυση",
+            classes: ["Tank", "Μαχητής", "Μάγος", "Δολοφόνος", "Σκοπευτής", "Υποστηρικτής"],
+            rarities: ["Ασημένια", "Χρυσή", "Πρισματική"],
+        },
+        Language::EsEs => &Table {
+            champion_tier: "Nivel {tier} con este campeón en {games} partidas",
+            class_tier: "Nivel {tier} para {class} en {games} partidas",
+            global_tier: "Nivel {tier} en general",
+            low_sample: "Pocas partidas ({games}), se usa sobre todo el nivel general",
+            no_champion_data: "Sin datos del campeón, se usa el nivel general",
+            synergy: "Sinergia con tu elección anterior {name} ({tier})",
+            rarity: "Aumento {rarity}",
+            classes: ["tanques", "luchadores", "magos", "asesinos", "tiradores", "soportes"],
+            rarities: ["de plata", "de oro", "prismático"],
+        },
+        Language::FrFr => &Table {
+            champion_tier: "Tier {tier} sur ce champion sur {games} parties",
+            class_tier: "Tier {tier} pour les {class} sur {games} parties",
+            global_tier: "Tier {tier} global",
+            low_sample: "Peu de parties ({games}), tier global privilégié",
+            no_champion_data: "Aucune donnée de champion, tier global utilisé",
+            synergy: "Synergie avec votre choix précédent {name} ({tier})",
+            rarity: "Augment {rarity}",
+            classes: ["tanks", "combattants", "mages", "assassins", "tireurs", "supports"],
+            rarities: ["argent", "or", "prismatique"],
+        },
+        Language::HuHu => &Table {
+            champion_tier: "{tier} szint ezzel a hőssel {games} játék alapján",
+            class_tier: "{tier} szint a(z) {class} osztályban {games} játék alapján",
+            global_tier: "{tier} szint összesítve",
+            low_sample: "Kevés játék ({games}), főleg az összesített szint",
+            no_champion_data: "Nincs hősadat, az összesített szint számít",
+            synergy: "Szinergia a korábbi választásoddal: {name} ({tier})",
+            rarity: "{rarity} fejlesztés",
+            classes: ["tank", "harcos", "mágus", "orgyilkos", "lövész", "támogató"],
+            rarities: ["Ezüst", "Arany", "Prizmatikus"],
+        },
+        Language::IdId => &Table {
+            champion_tier: "Tier {tier} pada champion ini dari {games} game",
+            class_tier: "Tier {tier} untuk {class} dari {games} game",
+            global_tier: "Tier {tier} keseluruhan",
+            low_sample: "Sampel sedikit ({games} game), mengandalkan tier keseluruhan",
+            no_champion_data: "Tidak ada data champion, memakai tier keseluruhan",
+            synergy: "Bersinergi dengan pilihan sebelumnya {name} ({tier})",
+            rarity: "Augment {rarity}",
+            classes: ["tank", "fighter", "mage", "assassin", "marksman", "support"],
+            rarities: ["Silver", "Gold", "Prismatic"],
+        },
+        Language::ItIt => &Table {
+            champion_tier: "Tier {tier} con questo campione su {games} partite",
+            class_tier: "Tier {tier} per {class} su {games} partite",
+            global_tier: "Tier {tier} complessivo",
+            low_sample: "Poche partite ({games}), prevale il tier complessivo",
+            no_champion_data: "Nessun dato sul campione, uso il tier complessivo",
+            synergy: "Sinergia con la tua scelta precedente {name} ({tier})",
+            rarity: "Potenziamento {rarity}",
+            classes: ["tank", "combattenti", "maghi", "assassini", "tiratori", "supporti"],
+            rarities: ["d'argento", "d'oro", "prismatico"],
+        },
+        Language::JaJp => &Table {
+            champion_tier: "このチャンピオンで{tier}ティア（{games}試合）",
+            class_tier: "{class}平均で{tier}ティア（{games}試合）",
+            global_tier: "全体で{tier}ティア",
+            low_sample: "試合数が少ない（{games}試合）ため全体ティア重視",
+            no_champion_data: "チャンピオンデータなし、全体ティアを使用",
+            synergy: "以前選んだ{name}とシナジー（{tier}）",
+            rarity: "{rarity}オーグメント",
+            classes: ["タンク", "ファイター", "メイジ", "アサシン", "マークスマン", "サポート"],
+            rarities: ["シルバー", "ゴールド", "プリズム"],
+        },
+        Language::KoKr => &Table {
+            champion_tier: "이 챔피언 기준 {tier} 티어 ({games}게임)",
+            class_tier: "{class} 평균 {tier} 티어 ({games}게임)",
+            global_tier: "전체 {tier} 티어",
+            low_sample: "표본 적음 ({games}게임), 전체 티어 위주",
+            no_champion_data: "챔피언 데이터 없음, 전체 티어 사용",
+            synergy: "이전에 고른 {name}와(과) 시너지 {tier}",
+            rarity: "{rarity} 증강",
+            classes: ["탱커", "전사", "마법사", "암살자", "원거리 딜러", "서포터"],
+            rarities: ["실버", "골드", "프리즘"],
+        },
+        Language::PlPl => &Table {
+            champion_tier: "Poziom {tier} na tym bohaterze (gry: {games})",
+            class_tier: "Poziom {tier} dla klasy {class} (gry: {games})",
+            global_tier: "Poziom {tier} ogólnie",
+            low_sample: "Mała próba (gry: {games}), głównie poziom ogólny",
+            no_champion_data: "Brak danych bohatera, użyto poziomu ogólnego",
+            synergy: "Synergia z wcześniejszym wyborem {name} ({tier})",
+            rarity: "{rarity} ulepszenie",
+            classes: ["tank", "wojownik", "mag", "zabójca", "strzelec", "wsparcie"],
+            rarities: ["Srebrne", "Złote", "Pryzmatyczne"],
+        },
+        Language::PtBr => &Table {
+            champion_tier: "Tier {tier} com este campeão em {games} partidas",
+            class_tier: "Tier {tier} para {class} em {games} partidas",
+            global_tier: "Tier {tier} geral",
+            low_sample: "Poucas partidas ({games}), priorizando o tier geral",
+            no_champion_data: "Sem dados do campeão, usando o tier geral",
+            synergy: "Sinergia com sua escolha anterior {name} ({tier})",
+            rarity: "Aumento {rarity}",
+            classes: ["tanques", "lutadores", "magos", "assassinos", "atiradores", "suportes"],
+            rarities: ["de prata", "de ouro", "prismático"],
+        },
+        Language::RoRo => &Table {
+            champion_tier: "Nivel {tier} cu acest campion (meciuri: {games})",
+            class_tier: "Nivel {tier} pentru clasa {class} (meciuri: {games})",
+            global_tier: "Nivel {tier} general",
+            low_sample: "Puține meciuri ({games}), mai ales nivelul general",
+            no_champion_data: "Fără date despre campion, se folosește nivelul general",
+            synergy: "Sinergie cu alegerea anterioară {name} ({tier})",
+            rarity: "Augment {rarity}",
+            classes: ["tanc", "luptător", "mag", "asasin", "trăgător", "suport"],
+            rarities: ["de argint", "de aur", "prismatic"],
+        },
+        Language::RuRu => &Table {
+            champion_tier: "Тир {tier} на этом чемпионе (игр: {games})",
+            class_tier: "Тир {tier} для класса «{class}» (игр: {games})",
+            global_tier: "Общий тир {tier}",
+            low_sample: "Мало игр ({games}), опора на общий тир",
+            no_champion_data: "Нет данных по чемпиону, используется общий тир",
+            synergy: "Синергия с ранее выбранным {name} ({tier})",
+            rarity: "{rarity} усиление",
+            classes: ["танк", "воин", "маг", "убийца", "стрелок", "поддержка"],
+            rarities: ["Серебряное", "Золотое", "Призматическое"],
+        },
+        Language::ThTh => &Table {
+            champion_tier: "ระดับ {tier} กับแชมเปี้ยนนี้ ({games} เกม)",
+            class_tier: "ระดับ {tier} สำหรับสาย{class} ({games} เกม)",
+            global_tier: "ระดับ {tier} โดยรวม",
+            low_sample: "ตัวอย่างน้อย ({games} เกม) อิงระดับโดยรวม",
+            no_champion_data: "ไม่มีข้อมูลแชมเปี้ยน ใช้ระดับโดยรวม",
+            synergy: "เข้ากันกับ {name} ที่เลือกไว้ก่อนหน้า ({tier})",
+            rarity: "ออคเมนต์ระดับ{rarity}",
+            classes: ["แทงค์", "ไฟเตอร์", "เมจ", "แอสซาซิน", "มาร์กสแมน", "ซัพพอร์ต"],
+            rarities: ["เงิน", "ทอง", "ปริซึม"],
+        },
+        Language::TrTr => &Table {
+            champion_tier: "Bu şampiyonla {games} oyunda {tier} seviye",
+            class_tier: "{class} sınıfında {games} oyunda {tier} seviye",
+            global_tier: "Genel {tier} seviye",
+            low_sample: "Az oyun ({games}), ağırlıklı genel seviye",
+            no_champion_data: "Şampiyon verisi yok, genel seviye kullanılıyor",
+            synergy: "Önceki seçimin {name} ile sinerji ({tier})",
+            rarity: "{rarity} güçlendirme",
+            classes: ["tank", "dövüşçü", "büyücü", "suikastçı", "nişancı", "destek"],
+            rarities: ["Gümüş", "Altın", "Prizmatik"],
+        },
+        Language::ViVn => &Table {
+            champion_tier: "Bậc {tier} với tướng này qua {games} trận",
+            class_tier: "Bậc {tier} cho {class} qua {games} trận",
+            global_tier: "Bậc {tier} tổng thể",
+            low_sample: "Ít trận ({games}), dựa chủ yếu vào bậc tổng thể",
+            no_champion_data: "Không có dữ liệu tướng, dùng bậc tổng thể",
+            synergy: "Cộng hưởng với lựa chọn trước {name} ({tier})",
+            rarity: "Lõi {rarity}",
+            classes: ["đỡ đòn", "đấu sĩ", "pháp sư", "sát thủ", "xạ thủ", "hỗ trợ"],
+            rarities: ["Bạc", "Vàng", "Lăng Kính"],
+        },
+        Language::ZhCn => &Table {
+            champion_tier: "该英雄{tier}级（{games}场）",
+            class_tier: "{class}平均{tier}级（{games}场）",
+            global_tier: "总体{tier}级",
+            low_sample: "样本较少（{games}场），以总体等级为主",
+            no_champion_data: "无英雄数据，使用总体等级",
+            synergy: "与之前选择的{name}协同（{tier}）",
+            rarity: "{rarity}强化符文",
+            classes: ["坦克", "战士", "法师", "刺客", "射手", "辅助"],
+            rarities: ["白银", "黄金", "棱彩"],
+        },
+        Language::ZhTw => &Table {
+            champion_tier: "此英雄{tier}級（{games}場）",
+            class_tier: "{class}平均{tier}級（{games}場）",
+            global_tier: "整體{tier}級",
+            low_sample: "樣本較少（{games}場），以整體等級為主",
+            no_champion_data: "無英雄資料，使用整體等級",
+            synergy: "與先前選擇的{name}協同（{tier}）",
+            rarity: "{rarity}強化",
+            classes: ["坦克", "鬥士", "法師", "刺客", "射手", "輔助"],
+            rarities: ["白銀", "黃金", "稜彩"],
+        },
+        // English, and what every language without a table reads through its fallback chain.
+        _ => &Table {
+            champion_tier: "{tier} tier on this champion over {games} games",
+            class_tier: "{tier} tier for {class} over {games} games",
+            global_tier: "{tier} tier overall",
+            low_sample: "Low sample ({games} games), leaning on global tier",
+            no_champion_data: "No champion data, using global tier",
+            synergy: "Synergizes with your earlier pick {name} ({tier})",
+            rarity: "{rarity} augment",
+            classes: ["tanks", "fighters", "mages", "assassins", "marksmen", "supports"],
+            rarities: ["Silver", "Gold", "Prismatic"],
+        },
+    }
+}
+
+pub fn group_thousands(n: i32) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        out.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
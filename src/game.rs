@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use crate::data::CHAMPIONS_DATA;
+use crate::live_client::{AllGameData, Backoff, GameDataSource, LiveClient, LiveClientError};
+use crate::rules::{active_score_rules, RuleContext, ScoreRule};
+use crate::session::GameSession;
+use crate::teamcomp::{active_rules, CompRule, TeamComp};
+use crate::timing::AugmentTiming;
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+pub const MAX_POLL_INTERVAL: Duration = Duration::from_secs(16);
+pub const CAPTURE_INTERVAL: Duration = Duration::from_millis(50);
+// Capture rate once a window timed out without a pick, so a late one is still read.
+pub const FALLBACK_CAPTURE_INTERVAL: Duration = Duration::from_secs(3);
+/// Consecutive 404s that count as the game having ended; a single one can be a hiccup while it loads.
+pub const END_AFTER_NOT_FOUND: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollEvent {
+    /// No game yet, or a poll failed without ending the current one.
+    Idle,
+    /// A game just came up; per-game files should be reloaded now.
+    Started,
+    InGame,
+    /// The game closed and its state was dropped.
+    Ended,
+}
+
+#[derive(Debug)]
+pub struct PollResult {
+    pub event: PollEvent,
+    pub error: Option<LiveClientError>,
+}
+
+/// Everything tracked for the running game, dropped when it ends.
+#[derive(Debug)]
+pub struct Game {
+    pub version: Option<String>,
+    pub mayhem: bool,
+    pub champion: Option<String>,
+    pub session: GameSession,
+    pub timing: AugmentTiming,
+    pub comp: Option<TeamComp>,
+    pub comp_rules: Vec<CompRule>,
+    pub score_rules: Vec<ScoreRule>,
+    active_player_name: Option<String>,
+}
+
+impl Game {
+    fn new(augment_levels: &[i32]) -> Self {
+        Self {
+            version: None,
+            mayhem: false,
+            champion: None,
+            session: GameSession::new(),
+            timing: AugmentTiming::new(augment_levels),
+            comp: None,
+            comp_rules: Vec::new(),
+            score_rules: Vec::new(),
+            active_player_name: None,
+        }
+    }
+
+    /// Whether an augment panel can be on screen, so captures are worth running.
+    pub fn is_window_open(&self) -> bool {
+        self.timing.is_open(self.session.picks().len()) || self.session.is_panel_open() || self.session.sees_cards()
+    }
+
+    /// How often to capture the screen, or `None` when no augment can be offered.
+    pub fn capture_interval(&self) -> Option<Duration> {
+        if self.is_window_open() {
+            Some(CAPTURE_INTERVAL)
+        } else if self.timing.is_pending(self.session.picks().len()) {
+            Some(FALLBACK_CAPTURE_INTERVAL)
+        } else {
+            None
+        }
+    }
+}
+
+/// Follows the game's lifecycle through a `GameDataSource`: start, per-poll updates and end.
+pub struct GameTracker {
+    augment_levels: Vec<i32>,
+    team_rules: Vec<CompRule>,
+    score_rules: Vec<ScoreRule>,
+    backoff: Backoff,
+    not_found: u32,
+    game: Option<Game>,
+}
+
+impl GameTracker {
+    pub fn new(augment_levels: &[i32], team_rules: Vec<CompRule>) -> Self {
+        Self {
+            augment_levels: augment_levels.to_vec(),
+            team_rules,
+            score_rules: Vec::new(),
+            backoff: Backoff::new(POLL_INTERVAL, MAX_POLL_INTERVAL),
+            not_found: 0,
+            game: None,
+        }
+    }
+
+    pub fn game(&self) -> Option<&Game> {
+        self.game.as_ref()
+    }
+
+    pub fn game_mut(&mut self) -> Option<&mut Game> {
+        self.game.as_mut()
+    }
+
+    /// How long to wait before the next `poll`.
+    pub fn poll_delay(&self) -> Duration {
+        self.backoff.delay()
+    }
+
+    /// Replaces the user's score rules, e.g. after reloading them on `Started`.
+    pub fn set_score_rules(&mut self, rules: Vec<ScoreRule>) {
+        self.score_rules = rules;
+        if let Some(game) = &mut self.game {
+            refresh_score_rules(game, &self.score_rules);
+        }
+    }
+
+    pub async fn poll<G: GameDataSource>(&mut self, source: &G) -> PollResult {
+        let mut game_data = match source.poll_game_data().await {
+            Ok(game_data) => game_data,
+            Err(error) => {
+                if matches!(error, LiveClientError::NotRunning(_)) {
+                    self.backoff.fail();
+                } else {
+                    self.backoff.reset();
+                }
+
+                if matches!(error, LiveClientError::Status(404)) {
+                    self.not_found += 1;
+                } else {
+                    self.not_found = 0;
+                }
+
+                // Timeouts, bad payloads and server errors mid-game are skipped; only a closed client or a
+                // run of 404s ends the session.
+                let ended = matches!(error, LiveClientError::NotRunning(_)) || self.not_found >= END_AFTER_NOT_FOUND;
+                let event = if ended && self.game.take().is_some() { PollEvent::Ended } else { PollEvent::Idle };
+                return PollResult { event, error: Some(error) };
+            }
+        };
+        self.backoff.reset();
+        self.not_found = 0;
+
+        let event = match &self.game {
+            Some(_) => PollEvent::InGame,
+            None => PollEvent::Started,
+        };
+        let game = self.game.get_or_insert_with(|| Game::new(&self.augment_levels));
+        if event == PollEvent::Started {
+            game.version = source.get_game_version().await.ok();
+        }
+
+        // Some payloads carry no name for the active player; the dedicated endpoint still knows it.
+        if game_data.active_player.identities().is_empty() {
+            if game.active_player_name.is_none() {
+                game.active_player_name = source.get_active_player_name().await.ok();
+            }
+            game_data.active_player.riot_id = game.active_player_name.clone();
+        }
+
+        game.mayhem = LiveClient::is_mayhem_mode(&game_data);
+        if game.mayhem {
+            update(game, &game_data, &self.team_rules, &self.score_rules);
+        }
+
+        PollResult { event, error: None }
+    }
+}
+
+fn update(game: &mut Game, game_data: &AllGameData, team_rules: &[CompRule], score_rules: &[ScoreRule]) {
+    game.timing.update(game_data);
+
+    game.comp = TeamComp::from_game(game_data);
+    if let Some(comp) = &game.comp {
+        game.comp_rules = active_rules(team_rules, comp, &CHAMPIONS_DATA);
+    }
+
+    if let Some(champion) = LiveClient::get_my_champion(game_data) {
+        game.champion = Some(champion);
+    }
+
+    refresh_score_rules(game, score_rules);
+}
+
+fn refresh_score_rules(game: &mut Game, score_rules: &[ScoreRule]) {
+    let Some(champion) = &game.champion else {
+        return;
+    };
+    game.score_rules = active_score_rules(score_rules, &RuleContext {
+        champion,
+        picked: &game.session.picked_ids(),
+        comp: game.comp.as_ref(),
+        champions: &CHAMPIONS_DATA,
+    });
+}
@@ -0,0 +1,323 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::data::{Augment, Champion, MayhemData, Rarity, Synergy, TIERS};
+use crate::metadata::DataMetadata;
+use crate::scoring::{wilson_interval, Z_95};
+
+// Fewer games than this say too little about an augment to give it a tier.
+const DEFAULT_MIN_GAMES: i32 = 100;
+
+// Upper bound of the win rate percentile for each tier in `TIERS`, best first.
+const TIER_PERCENTILES: [f64; 6] = [0.10, 0.25, 0.50, 0.75, 0.90, 1.0];
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AugmentStatsRow {
+    pub id: i32,
+    pub games: i32,
+    pub wins: i32,
+    #[serde(default)]
+    pub picks: Option<i32>,
+    // Replaces the rarity from `names` when the export has one.
+    #[serde(default)]
+    pub rarity: Option<Rarity>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChampionStatsRow {
+    pub champion: String,
+    pub id: i32,
+    pub games: i32,
+    pub wins: i32,
+    #[serde(default)]
+    pub picks: Option<i32>,
+}
+
+// Games where both augments were picked, in either order.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SynergyStatsRow {
+    pub a: i32,
+    pub b: i32,
+    pub games: i32,
+    pub wins: i32,
+}
+
+#[derive(Debug)]
+pub enum IngestError {
+    Io(String, std::io::Error),
+    Csv(String, csv::Error),
+    Json(String, serde_json::Error),
+    UnsupportedFormat(String),
+    InvalidRow(String),
+    UnknownAugment(i32),
+    DuplicateAugment(i32),
+    DuplicateChampionAugment(String, i32),
+    DuplicateSynergy(i32, i32),
+}
+
+impl fmt::Display for IngestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IngestError::Io(path, e) => write!(f, "{}: {}", path, e),
+            IngestError::Csv(path, e) => write!(f, "{}: {}", path, e),
+            IngestError::Json(path, e) => write!(f, "{}: {}", path, e),
+            IngestError::UnsupportedFormat(path) => write!(f, "{}: expected a .csv or .json file", path),
+            IngestError::InvalidRow(msg) => write!(f, "invalid row: {}", msg),
+            IngestError::UnknownAugment(id) => write!(f, "augment {} has stats but no localized names", id),
+            IngestError::DuplicateAugment(id) => write!(f, "augment {} has more than one stats row", id),
+            IngestError::DuplicateChampionAugment(champion, id) => {
+                write!(f, "{} has more than one stats row for augment {}", champion, id)
+            }
+            IngestError::DuplicateSynergy(a, b) => write!(f, "augments {} and {} have more than one pair row", a, b),
+        }
+    }
+}
+
+impl std::error::Error for IngestError {}
+
+pub fn read_rows<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, IngestError> {
+    let display = path.display().to_string();
+    let extension = path.extension().and_then(|e| e.to_str()).map(str::to_lowercase);
+
+    match extension.as_deref() {
+        Some("csv") => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_path(path)
+                .map_err(|e| IngestError::Csv(display.clone(), e))?;
+            reader
+                .deserialize()
+                .collect::<Result<Vec<T>, _>>()
+                .map_err(|e| IngestError::Csv(display, e))
+        }
+        Some("json") => {
+            let text = std::fs::read_to_string(path).map_err(|e| IngestError::Io(display.clone(), e))?;
+            serde_json::from_str(&text).map_err(|e| IngestError::Json(display, e))
+        }
+        _ => Err(IngestError::UnsupportedFormat(display)),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BuildOptions {
+    pub min_games: i32,
+    pub metadata: Option<DataMetadata>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self { min_games: DEFAULT_MIN_GAMES, metadata: None }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct RawStats {
+    id: i32,
+    games: i32,
+    wins: i32,
+    picks: Option<i32>,
+}
+
+struct Scored {
+    id: i32,
+    games: i32,
+    win_rate: f64,
+    // Lower bound of the win rate's 95% interval; ranks by it so a lucky small sample can't top the list.
+    lower_bound: f64,
+    popularity: String,
+}
+
+pub fn build_mayhem_data(
+    names: &MayhemData,
+    augment_rows: &[AugmentStatsRow],
+    champion_rows: &[ChampionStatsRow],
+    synergy_rows: &[SynergyStatsRow],
+    options: &BuildOptions,
+) -> Result<MayhemData, IngestError> {
+    // Exports split by region or date repeat rows; merging them is the exporter's job, not a guess here.
+    let mut seen = HashSet::new();
+    for row in augment_rows {
+        check_row(row.id, row.games, row.wins, row.picks)?;
+        if !seen.insert(row.id) {
+            return Err(IngestError::DuplicateAugment(row.id));
+        }
+    }
+    let mut seen = HashSet::new();
+    for row in champion_rows {
+        check_row(row.id, row.games, row.wins, row.picks)?;
+        let champion = row.champion.trim().to_lowercase();
+        if !seen.insert((champion.clone(), row.id)) {
+            return Err(IngestError::DuplicateChampionAugment(champion, row.id));
+        }
+    }
+    let mut seen = HashSet::new();
+    for row in synergy_rows {
+        check_row(row.a, row.games, row.wins, None)?;
+        if row.a == row.b {
+            return Err(IngestError::InvalidRow(format!("augment {} paired with itself", row.a)));
+        }
+        let pair = (row.a.min(row.b), row.a.max(row.b));
+        if !seen.insert(pair) {
+            return Err(IngestError::DuplicateSynergy(pair.0, pair.1));
+        }
+    }
+
+    let mut augments: Vec<Augment> = names
+        .augments
+        .iter()
+        .map(|a| Augment {
+            id: a.id,
+            name: a.name.clone(),
+            tier: None,
+            popularity: None,
+            games: None,
+            win_rate: None,
+            rarity: a.rarity,
+        })
+        .collect();
+    augments.sort_by_key(|a| a.id);
+
+    let index: HashMap<i32, usize> = augments.iter().enumerate().map(|(i, a)| (a.id, i)).collect();
+
+    let rows: Vec<_> = augment_rows
+        .iter()
+        .map(|r| RawStats { id: r.id, games: r.games, wins: r.wins, picks: r.picks })
+        .collect();
+    for (scored, tier) in score_scope(&rows, options) {
+        let i = *index.get(&scored.id).ok_or(IngestError::UnknownAugment(scored.id))?;
+        augments[i].tier = tier.map(str::to_string);
+        augments[i].popularity = Some(scored.popularity);
+        augments[i].games = Some(scored.games);
+        augments[i].win_rate = Some(scored.win_rate);
+    }
+    for row in augment_rows {
+        if let Some(rarity) = row.rarity {
+            augments[index[&row.id]].rarity = Some(rarity);
+        }
+    }
+
+    let mut by_champion: HashMap<String, Vec<RawStats>> = HashMap::new();
+    for row in champion_rows {
+        if !index.contains_key(&row.id) {
+            return Err(IngestError::UnknownAugment(row.id));
+        }
+        by_champion
+            .entry(row.champion.trim().to_lowercase())
+            .or_default()
+            .push(RawStats { id: row.id, games: row.games, wins: row.wins, picks: row.picks });
+    }
+
+    let mut champions = HashMap::new();
+    for (champion, rows) in by_champion {
+        let mut entries: Vec<Champion> = score_scope(&rows, options)
+            .into_iter()
+            .filter_map(|(scored, tier)| {
+                tier.map(|tier| Champion {
+                    id: scored.id,
+                    tier: tier.to_string(),
+                    popularity: scored.popularity,
+                    games: scored.games,
+                    win_rate: Some(scored.win_rate),
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| b.games.cmp(&a.games).then(a.id.cmp(&b.id)));
+
+        if !entries.is_empty() {
+            champions.insert(champion, entries);
+        }
+    }
+
+    // Pairs are tiered among themselves; the row index stands in for an id while scoring.
+    let mut pairs = Vec::with_capacity(synergy_rows.len());
+    let mut rows = Vec::with_capacity(synergy_rows.len());
+    for (i, row) in synergy_rows.iter().enumerate() {
+        for id in [row.a, row.b] {
+            if !index.contains_key(&id) {
+                return Err(IngestError::UnknownAugment(id));
+            }
+        }
+        pairs.push([row.a.min(row.b), row.a.max(row.b)]);
+        rows.push(RawStats { id: i as i32, games: row.games, wins: row.wins, picks: None });
+    }
+
+    let mut synergies: Vec<Synergy> = score_scope(&rows, options)
+        .into_iter()
+        .filter_map(|(scored, tier)| {
+            tier.map(|tier| Synergy {
+                augments: pairs[scored.id as usize],
+                tier: tier.to_string(),
+                games: scored.games,
+                win_rate: Some(scored.win_rate),
+            })
+        })
+        .collect();
+    synergies.sort_by_key(|s| s.augments);
+
+    Ok(MayhemData {
+        metadata: options.metadata.clone(),
+        champions,
+        augments: augments.into_iter().map(Arc::new).collect(),
+        synergies,
+    })
+}
+
+fn check_row(id: i32, games: i32, wins: i32, picks: Option<i32>) -> Result<(), IngestError> {
+    if games < 0 || wins < 0 || wins > games || picks.is_some_and(|p| p < 0) {
+        return Err(IngestError::InvalidRow(format!(
+            "augment {}: games={} wins={} picks={:?}",
+            id, games, wins, picks
+        )));
+    }
+    Ok(())
+}
+
+fn score_scope(rows: &[RawStats], options: &BuildOptions) -> Vec<(Scored, Option<&'static str>)> {
+    let total_picks: i64 = rows.iter().map(|r| r.picks.unwrap_or(r.games) as i64).sum();
+
+    let mut scored: Vec<Scored> = rows
+        .iter()
+        .map(|&RawStats { id, games, wins, picks }| {
+            let picks = picks.unwrap_or(games) as f64;
+            let share = if total_picks > 0 { picks / total_picks as f64 * 100.0 } else { 0.0 };
+            let win_rate = if games > 0 { wins as f64 / games as f64 } else { 0.0 };
+            Scored {
+                id,
+                games,
+                win_rate: round4(win_rate),
+                lower_bound: wilson_interval(win_rate, games, Z_95).0,
+                popularity: format!("{:.2}%", share),
+            }
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.lower_bound.total_cmp(&a.lower_bound).then(a.id.cmp(&b.id)));
+
+    let ranked = scored.iter().filter(|s| s.games >= options.min_games).count();
+    let mut rank = 0;
+
+    scored
+        .into_iter()
+        .map(|s| {
+            if s.games < options.min_games || ranked == 0 {
+                return (s, None);
+            }
+            let percentile = (rank + 1) as f64 / ranked as f64;
+            rank += 1;
+            let tier = TIER_PERCENTILES
+                .iter()
+                .position(|&p| percentile <= p)
+                .map(|i| TIERS[i]);
+            (s, tier)
+        })
+        .collect()
+}
+
+fn round4(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
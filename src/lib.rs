@@ -1,11 +1,40 @@
+pub mod compiled;
+pub mod config;
 pub mod data;
+pub mod diff;
+pub mod explain;
+pub mod game;
+pub mod ingest;
 pub mod live_client;
+pub mod log;
+pub mod metadata;
+pub mod reroll;
+pub mod resolver;
+pub mod rules;
+#[cfg(windows)]
 pub mod capture;
 pub mod overlay;
+pub mod preferences;
+pub mod rarity;
+pub mod recommend;
+pub mod scoring;
+pub mod session;
+pub mod stats;
+pub mod store;
+pub mod teamcomp;
+pub mod timing;
+pub mod tls;
+#[cfg(windows)]
 pub mod tray;
+pub mod validate;
 
 use image::DynamicImage;
+#[cfg(windows)]
 use capture::{get_lol_window, capture_region, capture_screen};
+#[cfg(windows)]
+use data::Rarity;
+#[cfg(windows)]
+use rarity::offered_rarity;
 
 const PANEL_Y_RATIO: f32 = 0.1670;
 const PANEL_H_RATIO: f32 = 0.4870;
@@ -14,10 +43,15 @@ const TEXT_Y_OFFSET_RATIO: f32 = 0.3775;
 const TEXT_H_RATIO: f32 = 0.2066;
 const CARD_GAP_RATIO: f32 = 0.035;
 const CARD_BEZEL_RATIO: f32 = 0.07;
+// The card's outer border, which is colored by rarity, as a share of the card width.
+const FRAME_X_RATIO: f32 = 0.01;
+const FRAME_W_RATIO: f32 = 0.03;
 
-pub fn capture_augment_cards() -> [Option<DynamicImage>; 3] {
+/// The title strip of each offered card, and the offer's rarity read off the card frames.
+#[cfg(windows)]
+pub fn capture_augment_cards() -> ([Option<DynamicImage>; 3], Option<Rarity>) {
     let Some(game) = get_lol_window() else {
-        return [None, None, None];
+        return ([None, None, None], None);
     };
 
     let h = game.height as f32;
@@ -41,28 +75,37 @@ pub fn capture_augment_cards() -> [Option<DynamicImage>; 3] {
     let capture_h = text_height as i32;
 
     let Some(img) = capture_region(capture_x, capture_y, capture_w, capture_h) else {
-        return [None, None, None];
+        return ([None, None, None], None);
     };
 
+    let frames = [0.0, 1.0, 2.0].map(|i| {
+        let left = i * (card_width + gap) + card_width * FRAME_X_RATIO;
+        img.crop_imm(left as u32, 0, (card_width * FRAME_W_RATIO) as u32, text_height as u32)
+    });
+    let rarity = offered_rarity(&frames);
+
     let card_width_full = card_width as u32;
     let bezel = bezel as u32;
     let card_width = card_width as u32 - bezel * 2;
     let gap = gap as u32;
     let text_height = text_height as u32;
 
-    [
+    let cards = [
         Some(img.crop_imm(bezel, 0, card_width, text_height)),
         Some(img.crop_imm(card_width_full + gap + bezel, 0, card_width, text_height)),
         Some(img.crop_imm((card_width_full + gap) * 2 + bezel, 0, card_width, text_height)),
-    ]
+    ];
+    (cards, rarity)
 }
 
-pub fn capture_augment_cards_fullscreen() -> [Option<DynamicImage>; 3] {
+#[cfg(windows)]
+pub fn capture_augment_cards_fullscreen() -> ([Option<DynamicImage>; 3], Option<Rarity>) {
     let Some(img) = capture_screen() else {
-        return [None, None, None];
+        return ([None, None, None], None);
     };
     let cards = crop_augment_cards(&img);
-    [Some(cards[0].clone()), Some(cards[1].clone()), Some(cards[2].clone())]
+    let rarity = offered_rarity(&crop_card_frames(&img));
+    ([Some(cards[0].clone()), Some(cards[1].clone()), Some(cards[2].clone())], rarity)
 }
 
 pub fn crop_augment_cards(img: &DynamicImage) -> [DynamicImage; 3] {
@@ -94,4 +137,26 @@ pub fn crop_augment_cards(img: &DynamicImage) -> [DynamicImage; 3] {
         img.crop_imm(panel_x + card_width_full + gap + bezel, text_y, card_width, text_height),
         img.crop_imm(panel_x + (card_width_full + gap) * 2 + bezel, text_y, card_width, text_height),
     ]
-}
\ No newline at end of file
+}
+
+/// A strip of each card's outer border from a full screenshot, level with the title.
+pub fn crop_card_frames(img: &DynamicImage) -> [DynamicImage; 3] {
+    let (screen_width, screen_height) = (img.width(), img.height());
+    let h = screen_height as f32;
+
+    let panel_h = h * PANEL_H_RATIO;
+    let panel_w = h * PANEL_W_RATIO;
+    let panel_y = h * PANEL_Y_RATIO;
+    let panel_x = (screen_width as f32 - panel_w) / 2.0;
+
+    let gap = panel_w * CARD_GAP_RATIO;
+    let card_width = (panel_w - gap * 2.0) / 3.0;
+
+    let text_y = panel_y + panel_h * TEXT_Y_OFFSET_RATIO;
+    let text_height = panel_h * TEXT_H_RATIO;
+
+    [0.0, 1.0, 2.0].map(|i| {
+        let left = panel_x + i * (card_width + gap) + card_width * FRAME_X_RATIO;
+        img.crop_imm(left as u32, text_y as u32, (card_width * FRAME_W_RATIO) as u32, text_height as u32)
+    })
+}
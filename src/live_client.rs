@@ -1,76 +1,519 @@
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
-const LIVE_CLIENT_URL: &str = "https://127.0.0.1:2999/liveclientdata/allgamedata";
+use crate::resolver::CHAMPION_RESOLVER;
+use crate::tls::{TlsError, TlsMode};
 
-#[derive(Debug, Clone, Deserialize)]
+pub const DEFAULT_BASE_URL: &str = "https://127.0.0.1:2999";
+const ALL_GAME_DATA_PATH: &str = "/liveclientdata/allgamedata";
+// A bare JSON string, the Riot ID on current clients.
+const ACTIVE_PLAYER_NAME_PATH: &str = "/liveclientdata/activeplayername";
+// The spec's `info.version` is the running game build, e.g. `15.22.719.4137`.
+const OPENAPI_PATH: &str = "/swagger/v3/openapi.json";
+// The API is on loopback, so anything slower than this is a hung or still-loading game.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Detail fields default so a renamed or missing one in a new client build doesn't fail the whole poll,
+// but the players and the game mode and time are required: without them the payload can't be used,
+// and a `Schema` error says so instead of the game silently reading as "not Mayhem".
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AllGameData {
+    #[serde(default)]
     pub active_player: ActivePlayer,
     pub all_players: Vec<Player>,
+    #[serde(default)]
+    pub events: Events,
     pub game_data: GameData,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ActivePlayer {
     pub summoner_name: String,
     pub riot_id: Option<String>,
+    pub riot_id_game_name: Option<String>,
+    pub riot_id_tag_line: Option<String>,
+    pub level: i32,
+    pub current_gold: f64,
+    pub abilities: Abilities,
+    pub champion_stats: ChampionStats,
+    pub full_runes: FullRunes,
+    pub team_relative_colors: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Abilities {
+    #[serde(rename = "Passive")]
+    pub passive: Ability,
+    #[serde(rename = "Q")]
+    pub q: Ability,
+    #[serde(rename = "W")]
+    pub w: Ability,
+    #[serde(rename = "E")]
+    pub e: Ability,
+    #[serde(rename = "R")]
+    pub r: Ability,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Ability {
+    // Absent for the passive.
+    pub ability_level: Option<i32>,
+    pub display_name: String,
+    pub id: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChampionStats {
+    pub ability_haste: f64,
+    pub ability_power: f64,
+    pub armor: f64,
+    pub armor_penetration_flat: f64,
+    pub armor_penetration_percent: f64,
+    pub attack_damage: f64,
+    pub attack_range: f64,
+    pub attack_speed: f64,
+    pub bonus_armor_penetration_percent: f64,
+    pub bonus_magic_penetration_percent: f64,
+    pub crit_chance: f64,
+    pub crit_damage: f64,
+    pub current_health: f64,
+    pub heal_shield_power: f64,
+    pub health_regen_rate: f64,
+    pub life_steal: f64,
+    pub magic_lethality: f64,
+    pub magic_penetration_flat: f64,
+    pub magic_penetration_percent: f64,
+    pub magic_resist: f64,
+    pub max_health: f64,
+    pub move_speed: f64,
+    pub omnivamp: f64,
+    pub physical_lethality: f64,
+    pub physical_vamp: f64,
+    pub resource_max: f64,
+    pub resource_regen_rate: f64,
+    pub resource_type: String,
+    pub resource_value: f64,
+    pub spell_vamp: f64,
+    pub tenacity: f64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FullRunes {
+    pub general_runes: Vec<Rune>,
+    pub keystone: Rune,
+    pub primary_rune_tree: Rune,
+    pub secondary_rune_tree: Rune,
+    pub stat_runes: Vec<StatRune>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Rune {
+    pub display_name: String,
+    pub id: i32,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct StatRune {
+    pub id: i32,
+    pub raw_description: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Player {
     pub summoner_name: String,
+    pub riot_id: Option<String>,
+    pub riot_id_game_name: Option<String>,
+    pub riot_id_tag_line: Option<String>,
     pub champion_name: String,
     pub raw_champion_name: String,
     pub team: String,
+    pub level: i32,
+    pub is_bot: bool,
+    pub is_dead: bool,
+    pub respawn_timer: f64,
+    pub position: String,
+    pub items: Vec<Item>,
+    pub scores: Scores,
+    pub runes: PlayerRunes,
+    pub summoner_spells: SummonerSpells,
+    #[serde(rename = "skinID")]
+    pub skin_id: i32,
+    pub skin_name: String,
+    pub raw_skin_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Item {
+    #[serde(rename = "itemID")]
+    pub item_id: i32,
+    pub display_name: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+    pub slot: i32,
+    pub count: i32,
+    pub price: i32,
+    pub can_use: bool,
+    pub consumable: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Scores {
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub creep_score: i32,
+    pub ward_score: f64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PlayerRunes {
+    pub keystone: Rune,
+    pub primary_rune_tree: Rune,
+    pub secondary_rune_tree: Rune,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SummonerSpells {
+    pub summoner_spell_one: SummonerSpell,
+    pub summoner_spell_two: SummonerSpell,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SummonerSpell {
+    pub display_name: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Events {
+    #[serde(rename = "Events")]
+    pub events: Vec<GameEvent>,
+}
+
+// Fields beyond these depend on the event kind and are kept as is.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GameEvent {
+    #[serde(rename = "EventID")]
+    pub id: i32,
+    #[serde(rename = "EventName")]
+    pub name: String,
+    #[serde(rename = "EventTime")]
+    pub time: f64,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventKind {
+    GameStart,
+    MinionsSpawning,
+    FirstBlood { recipient: String },
+    ChampionKill { killer: String, victim: String, assisters: Vec<String> },
+    Multikill { killer: String, streak: i32 },
+    Ace { acer: String, acing_team: String },
+    TurretKilled { killer: String, turret: String },
+    InhibKilled { killer: String, inhib: String },
+    FirstBrick { killer: String },
+    GameEnd { result: String },
+    Other(String),
+}
+
+impl GameEvent {
+    pub fn kind(&self) -> EventKind {
+        let text = |key: &str| self.extra.get(key).and_then(serde_json::Value::as_str).unwrap_or_default().to_string();
+
+        match self.name.as_str() {
+            "GameStart" => EventKind::GameStart,
+            "MinionsSpawning" => EventKind::MinionsSpawning,
+            "FirstBlood" => EventKind::FirstBlood { recipient: text("Recipient") },
+            "ChampionKill" => EventKind::ChampionKill {
+                killer: text("KillerName"),
+                victim: text("VictimName"),
+                assisters: self
+                    .extra
+                    .get("Assisters")
+                    .and_then(serde_json::Value::as_array)
+                    .map(|a| a.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+                    .unwrap_or_default(),
+            },
+            "Multikill" => EventKind::Multikill {
+                killer: text("KillerName"),
+                streak: self.extra.get("KillStreak").and_then(serde_json::Value::as_i64).unwrap_or(0) as i32,
+            },
+            "Ace" => EventKind::Ace { acer: text("Acer"), acing_team: text("AcingTeam") },
+            "TurretKilled" => EventKind::TurretKilled { killer: text("KillerName"), turret: text("TurretKilled") },
+            "InhibKilled" => EventKind::InhibKilled { killer: text("KillerName"), inhib: text("InhibKilled") },
+            "FirstBrick" => EventKind::FirstBrick { killer: text("KillerName") },
+            "GameEnd" => EventKind::GameEnd { result: text("Result") },
+            other => EventKind::Other(other.to_string()),
+        }
+    }
+}
+
+impl ActivePlayer {
+    /// Names to look this player up by, most specific first. Empty when the payload doesn't say (e.g. spectating).
+    pub fn identities(&self) -> Vec<String> {
+        identities(&self.riot_id, &self.riot_id_game_name, &self.riot_id_tag_line, &self.summoner_name)
+    }
+}
+
+impl Player {
+    pub fn identities(&self) -> Vec<String> {
+        identities(&self.riot_id, &self.riot_id_game_name, &self.riot_id_tag_line, &self.summoner_name)
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        self.identities().iter().any(|id| id.eq_ignore_ascii_case(name))
+    }
+}
+
+// `riotId`, then `gameName#tagLine`, then the summoner name, which newer clients leave empty or fill with the Riot ID.
+fn identities(riot_id: &Option<String>, game_name: &Option<String>, tag_line: &Option<String>, summoner_name: &str) -> Vec<String> {
+    let composed = match (game_name.as_deref(), tag_line.as_deref()) {
+        (Some(name), Some(tag)) if !name.trim().is_empty() && !tag.trim().is_empty() => {
+            Some(format!("{}#{}", name.trim(), tag.trim()))
+        }
+        _ => None,
+    };
+
+    let mut ids: Vec<String> = Vec::new();
+    for id in [riot_id.as_deref().map(str::trim), composed.as_deref(), Some(summoner_name.trim())].into_iter().flatten() {
+        if !id.is_empty() && !ids.iter().any(|known| known.eq_ignore_ascii_case(id)) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GameData {
     pub game_mode: String,
     pub game_time: f64,
+    #[serde(default)]
+    pub map_name: String,
+    #[serde(default)]
+    pub map_number: i32,
+    #[serde(default)]
+    pub map_terrain: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OpenApiSpec {
+    info: OpenApiInfo,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OpenApiInfo {
+    version: String,
+}
+
+#[derive(Debug)]
+pub enum LiveClientError {
+    /// Nothing listens on the port: the game isn't running.
+    NotRunning(reqwest::Error),
+    Timeout,
+    Tls(reqwest::Error),
+    Request(reqwest::Error),
+    /// Any other status, e.g. 404 while the game is still loading.
+    Status(u16),
+    /// The body didn't fit the expected shape; kept so the mismatch can be logged.
+    Schema { error: serde_json::Error, body: String },
+}
+
+impl fmt::Display for LiveClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiveClientError::NotRunning(_) => write!(f, "game is not running"),
+            LiveClientError::Timeout => write!(f, "request timed out"),
+            LiveClientError::Tls(e) => write!(f, "TLS error: {}", tls_cause(e).map_or_else(|| e.to_string(), |c| c.to_string())),
+            LiveClientError::Request(e) => write!(f, "{}", e),
+            LiveClientError::Status(status) => write!(f, "HTTP {}", status),
+            LiveClientError::Schema { error, .. } => write!(f, "unexpected response: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for LiveClientError {}
+
+impl From<reqwest::Error> for LiveClientError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            LiveClientError::Timeout
+        } else if tls_cause(&e).is_some() {
+            LiveClientError::Tls(e)
+        } else if e.is_connect() {
+            LiveClientError::NotRunning(e)
+        } else {
+            LiveClientError::Request(e)
+        }
+    }
+}
+
+// rustls errors reach reqwest wrapped in `io::Error`s, whose `source()` skips the wrapped error itself.
+fn tls_cause(e: &reqwest::Error) -> Option<&rustls::Error> {
+    let mut source = std::error::Error::source(e);
+    while let Some(error) = source {
+        if let Some(tls) = error.downcast_ref::<rustls::Error>() {
+            return Some(tls);
+        }
+        source = match error.downcast_ref::<std::io::Error>() {
+            Some(io) => io.get_ref().map(|inner| inner as &(dyn std::error::Error + 'static)),
+            None => error.source(),
+        };
+    }
+    None
+}
+
+/// Poll delay that doubles while the game isn't running, up to `max`, and snaps back once it answers.
+#[derive(Clone, Debug)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    delay: Duration,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self { base, max, delay: base }
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    pub fn fail(&mut self) {
+        self.delay = (self.delay * 2).min(self.max);
+    }
+
+    pub fn reset(&mut self) {
+        self.delay = self.base;
+    }
+}
+
+/// Where the running game's data comes from.
+pub trait GameDataSource {
+    fn poll_game_data(&self) -> impl Future<Output = Result<AllGameData, LiveClientError>> + Send;
+    fn get_game_version(&self) -> impl Future<Output = Result<String, LiveClientError>> + Send;
+    fn get_active_player_name(&self) -> impl Future<Output = Result<String, LiveClientError>> + Send;
 }
 
+/// The game client's Live Client Data API over HTTPS.
 pub struct LiveClient {
     client: Client,
+    base_url: String,
+}
+
+impl GameDataSource for LiveClient {
+    async fn poll_game_data(&self) -> Result<AllGameData, LiveClientError> {
+        self.get(ALL_GAME_DATA_PATH).await
+    }
+
+    async fn get_game_version(&self) -> Result<String, LiveClientError> {
+        self.get::<OpenApiSpec>(OPENAPI_PATH).await.map(|spec| spec.info.version)
+    }
+
+    async fn get_active_player_name(&self) -> Result<String, LiveClientError> {
+        self.get(ACTIVE_PLAYER_NAME_PATH).await
+    }
 }
 
 impl LiveClient {
-    pub fn new() -> Result<Self, reqwest::Error> {
-        let client = Client::builder()
-            .danger_accept_invalid_certs(true)
-            .build()?;
-
-        Ok(Self { client })
-    }
-
-    pub async fn poll_game_data(&self) -> Option<AllGameData> {
-        match self.client.get(LIVE_CLIENT_URL).send().await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    response.json::<AllGameData>().await.ok()
-                } else {
-                    None
-                }
-            }
-            Err(_) => None,
-        }
+    pub fn new() -> Result<Self, TlsError> {
+        Self::with_base_url(DEFAULT_BASE_URL)
     }
 
-    pub fn get_my_champion(game_data: &AllGameData) -> Option<String> {
-        let my_name = &game_data.active_player.summoner_name;
+    /// `base_url` is scheme, host and port, e.g. `https://127.0.0.1:2999`.
+    pub fn with_base_url(base_url: &str) -> Result<Self, TlsError> {
+        Self::with_tls(base_url, &TlsMode::RiotRoot)
+    }
+
+    pub fn with_tls(base_url: &str, tls: &TlsMode) -> Result<Self, TlsError> {
+        let builder = Client::builder().connect_timeout(CONNECT_TIMEOUT).timeout(REQUEST_TIMEOUT);
+        let builder = match tls.client_config()? {
+            Some(config) => builder.use_preconfigured_tls(config),
+            None => builder.danger_accept_invalid_certs(true),
+        };
+        let client = builder.build().map_err(TlsError::Client)?;
+
+        Ok(Self { client, base_url: base_url.trim_end_matches('/').to_string() })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
 
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, LiveClientError> {
+        let response = self.client.get(self.url(path)).send().await?;
+        if !response.status().is_success() {
+            return Err(LiveClientError::Status(response.status().as_u16()));
+        }
+
+        let body = response.text().await?;
+        serde_json::from_str(&body).map_err(|error| LiveClientError::Schema { error, body })
+    }
+
+    /// Tries each of the active player's identities in turn; a name shared by several players is skipped.
+    pub fn get_my_player(game_data: &AllGameData) -> Option<&Player> {
         game_data
-            .all_players
+            .active_player
+            .identities()
             .iter()
-            .find(|p| &p.summoner_name == my_name)
-            .map(|p| {
-                p.raw_champion_name
+            .find_map(|name| Self::find_player(game_data, name))
+    }
+
+    /// The one player going by `name` (a Riot ID or summoner name), e.g. from the `activeplayername` endpoint.
+    pub fn find_player<'a>(game_data: &'a AllGameData, name: &str) -> Option<&'a Player> {
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut matches = game_data.all_players.iter().filter(|p| p.is_named(name));
+        let player = matches.next()?;
+        matches.next().is_none().then_some(player)
+    }
+
+    pub fn get_my_champion(game_data: &AllGameData) -> Option<String> {
+        Self::get_my_player(game_data).map(Self::champion_id)
+    }
+
+    pub fn champion_id(player: &Player) -> String {
+        CHAMPION_RESOLVER
+            .resolve(&player.raw_champion_name)
+            .or_else(|| CHAMPION_RESOLVER.resolve(&player.champion_name))
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                player.raw_champion_name
                     .strip_prefix("game_character_displayname_")
-                    .unwrap_or(&p.raw_champion_name)
+                    .unwrap_or(&player.raw_champion_name)
                     .to_lowercase()
             })
     }
@@ -80,8 +523,3 @@ impl LiveClient {
     }
 }
 
-impl Default for LiveClient {
-    fn default() -> Self {
-        Self::new().expect("Failed to create LiveClient")
-    }
-}
@@ -0,0 +1,46 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::config_file;
+use crate::metadata::format_date;
+
+pub const LOG_FILE: &str = "aram-mayhem-advisor.log";
+// Past this the log starts over, so a long-running failure can't fill the disk.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Writes `message` to the log next to `config.json`, since the release build has no console.
+/// Returns the log's path when it was written.
+pub fn log(message: &str) -> Option<PathBuf> {
+    eprintln!("{}", message);
+    let path = config_file(LOG_FILE)?;
+    match append(&path, message) {
+        Ok(()) => Some(path),
+        Err(e) => {
+            eprintln!("Failed to write {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+pub fn append(path: &Path, message: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let full = std::fs::metadata(path).is_ok_and(|m| m.len() > MAX_LOG_BYTES);
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(!full)
+        .truncate(full)
+        .open(path)?;
+    writeln!(file, "[{}] {}", timestamp(), message)
+}
+
+// UTC, e.g. `2025-11-02 14:03:09`.
+fn timestamp() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or(0);
+    let time = secs.rem_euclid(86_400);
+    format!("{} {:02}:{:02}:{:02}", format_date(secs.div_euclid(86_400)), time / 3600, time / 60 % 60, time % 60)
+}
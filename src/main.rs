@@ -1,29 +1,54 @@
 #![cfg_attr(not(feature = "dev"), windows_subsystem = "windows")]
 
+#[cfg(windows)]
 use std::time::{Duration, Instant};
+#[cfg(windows)]
 use ocr_rs::{OcrEngine, OcrEngineConfig};
-use aram_mayhem_advisor::{capture_augment_cards_fullscreen, overlay::calculate_card_positions_fullscreen, capture_augment_cards, capture::is_lol_foreground, data::{find_augment, Language, MAYHEM_DATA, extract_title_from_ocr, get_champion_name}, live_client::LiveClient, overlay::{Overlay, AugmentDisplay, ChampionStats, calculate_card_positions}, tray::{Tray, TrayCommand}};
+#[cfg(windows)]
+use aram_mayhem_advisor::{capture_augment_cards_fullscreen, overlay::calculate_card_positions_fullscreen, capture_augment_cards, capture::is_lol_foreground, data::{find_augment, Language, MAYHEM_DATA, CHAMPIONS_DATA, extract_title_from_ocr, get_champion_name, Rarity}, game::{GameTracker, PollEvent}, live_client::{LiveClient, LiveClientError}, log::{log, LOG_FILE}, metadata::check_metadata, config::{config_file, AppConfig}, overlay::{Overlay, AugmentDisplay, ChampionStats, SynergyBadge, calculate_card_positions}, scoring::score_champion, stats::{resolve_stats, strong_synergies, ResolvedStats}, recommend::{CardInput, Recommender}, preferences::Preferences, rarity::SeenRarities, reroll::{RerollAdvisor, RerollInput}, rules::{load_score_rules, ScoreRule}, teamcomp::{load_rules, CompRule}, tray::{Tray, TrayCommand}};
 
-const POLL_INTERVAL: Duration = Duration::from_millis(1000);
-const CAPTURE_INTERVAL: Duration = Duration::from_millis(50);
+#[cfg(all(windows, feature = "dev"))]
+use aram_mayhem_advisor::game::CAPTURE_INTERVAL;
 
+#[cfg(windows)]
 const DET_MODEL: &[u8] = include_bytes!("../models/PP-OCRv5_mobile_det.mnn");
+#[cfg(windows)]
 const REC_MODEL: &[u8] = include_bytes!("../models/korean_PP-OCRv5_mobile_rec_infer.mnn");
+#[cfg(windows)]
 const CHARSET: &[u8] = include_bytes!("../models/ppocr_keys_korean.txt");
 
+#[cfg(windows)]
 fn create_engine() -> OcrEngine {
     let config = OcrEngineConfig::fast();
     OcrEngine::from_bytes(DET_MODEL, REC_MODEL, CHARSET, Some(config))
         .expect("Failed to initialize OCR engine")
 }
 
+#[cfg(windows)]
+struct CardContext<'a> {
+    champion: &'a str,
+    data_warning: Option<&'a str>,
+    picked: &'a [i32],
+    recommender: &'a Recommender,
+    comp_rules: &'a [CompRule],
+    score_rules: &'a [ScoreRule],
+    preferences: &'a Preferences,
+    rarity: Option<Rarity>,
+}
+
+#[cfg(windows)]
 fn process_cards(
     engine: &OcrEngine,
     cards: &[Option<image::DynamicImage>; 3],
-    current_champion: &str,
+    ctx: &CardContext,
+    reroll: &mut RerollAdvisor,
+    seen: &mut SeenRarities,
 ) -> ([Option<AugmentDisplay>; 3], bool) {
-    let mut augments: [Option<AugmentDisplay>; 3] = [None, None, None];
-    let mut found_any = false;
+    let CardContext { champion: current_champion, data_warning, picked, recommender, comp_rules, score_rules, preferences, rarity } = *ctx;
+    let (generation, data) = MAYHEM_DATA.snapshot();
+    let data_label = data.metadata.as_ref().and_then(|m| m.summary());
+    let champ_lower = current_champion.to_lowercase();
+    let mut matches = [None, None, None];
 
     for (i, card_opt) in cards.iter().enumerate() {
         let Some(card) = card_opt else { continue };
@@ -36,38 +61,150 @@ fn process_cards(
 
             let title = extract_title_from_ocr(&items, 10);
 
-            if !title.is_empty() {
-                if let Some(matched) = find_augment(&title, Language::KoKr, 0.85) {
-                    let champ_lower = current_champion.to_lowercase();
-                    let champ_display_name = get_champion_name(&champ_lower, Language::KoKr)
-                        .unwrap_or_else(|| current_champion.to_string());
-                    let champ_info = MAYHEM_DATA.champions.get(&champ_lower)
-                        .and_then(|augs| augs.iter().find(|a| a.id == matched.augment.id));
-
-                    let champion_stats = Some(ChampionStats {
-                        name: champ_display_name,
-                        tier: champ_info.map(|i| i.tier.clone()).unwrap_or_else(|| "-".to_string()),
-                        popularity: champ_info.map(|i| i.popularity.clone()).unwrap_or_else(|| "-".to_string()),
-                        games: champ_info.map(|i| i.games).unwrap_or(0),
-                    });
-
-                    augments[i] = Some(AugmentDisplay {
-                        name: matched.augment.name.ko_kr.clone(),
-                        tier: matched.augment.tier.clone().unwrap_or_else(|| "-".to_string()),
-                        popularity: matched.augment.popularity.clone().unwrap_or_else(|| "-".to_string()),
-                        games: matched.augment.games.unwrap_or(0),
-                        champion: champion_stats,
-                    });
-                    found_any = true;
-                }
+            if !title.is_empty() && let Some(matched) = find_augment(&title, Language::KoKr, 0.85) {
+                matches[i] = Some(matched.augment);
             }
         }
     }
 
+    let resolved = matches.each_ref().map(|m| m.as_ref().and_then(|a| resolve_stats(&data, &CHAMPIONS_DATA, &champ_lower, a)));
+    let strong = matches.each_ref().map(|m| m.as_ref().map(|a| strong_synergies(&data, a.id, picked)).unwrap_or_default());
+    let marks = matches.each_ref().map(|m| m.as_ref().and_then(|a| preferences.get(&champ_lower, a)));
+    let inputs: [Option<CardInput>; 3] = std::array::from_fn(|i| {
+        matches[i].as_deref().map(|augment| {
+            CardInput::new(augment)
+                .with_stats(resolved[i].as_ref())
+                .with_synergies(&strong[i])
+                .with_comp_rules(comp_rules)
+                .with_score_rules(score_rules)
+                .with_preference(marks[i].as_ref())
+        })
+    });
+    let scores = recommender.rank(&inputs);
+    let found_any = matches.iter().any(Option::is_some);
+
+    let mut augments: [Option<AugmentDisplay>; 3] = [None, None, None];
+    for (i, card) in inputs.iter().enumerate() {
+        let (Some(card), Some(score)) = (card, &scores[i]) else { continue };
+        let augment = card.augment;
+
+        let champ_display_name = get_champion_name(&champ_lower, Language::KoKr)
+            .unwrap_or_else(|| current_champion.to_string());
+        let champ_info = card.stats.map(|r| &r.stats);
+        let champ_score = champ_info.map(|info| score_champion(info, augment));
+
+        let champion_stats = Some(ChampionStats {
+            name: champ_display_name,
+            tier: champ_score.as_ref().map(|s| s.tier.clone()).unwrap_or_else(|| "-".to_string()),
+            raw_tier: champ_info.map(|i| i.tier.clone()).unwrap_or_else(|| "-".to_string()),
+            popularity: champ_info.map(|i| i.popularity.clone()).unwrap_or_else(|| "-".to_string()),
+            games: champ_info.map(|i| i.games).unwrap_or(0),
+            win_rate: champ_info.and_then(|i| i.win_rate),
+            win_rate_interval: champ_score.as_ref().and_then(|s| s.win_rate_interval),
+            confidence: card.stats.and_then(ResolvedStats::confidence),
+            level: card.stats.map(|r| r.level),
+            class: card.stats.and_then(|r| r.class),
+        });
+
+        let explanations = recommender
+            .explain(card)
+            .iter()
+            .map(|e| e.text(Language::KoKr, &data))
+            .collect();
+
+        let synergies = card
+            .synergies
+            .iter()
+            .filter_map(|s| {
+                let partner = s.partner(augment.id)?;
+                let partner = data.augments.iter().find(|a| a.id == partner)?;
+                Some(SynergyBadge {
+                    name: partner.name.get(Language::KoKr).to_string(),
+                    tier: s.tier.clone(),
+                    games: s.games,
+                })
+            })
+            .collect();
+
+        augments[i] = Some(AugmentDisplay {
+            id: augment.id,
+            name: augment.name.get(Language::KoKr).to_string(),
+            tier: augment.tier.clone().unwrap_or_else(|| "-".to_string()),
+            popularity: augment.popularity.clone().unwrap_or_else(|| "-".to_string()),
+            games: augment.games.unwrap_or(0),
+            win_rate: augment.win_rate,
+            champion: champion_stats,
+            synergies,
+            score: Some(score.score),
+            best: false,
+            reroll: None,
+            mark: card.preference.and_then(|p| p.mark),
+            note: card.preference.and_then(|p| p.note.clone()),
+            explanations,
+            data_label: data_label.clone(),
+            data_warning: data_warning.map(str::to_string),
+        });
+    }
+
+    if scores.iter().flatten().count() > 1
+        && let Some(best) = Recommender::best(&scores)
+        && let Some(augment) = &mut augments[best]
+    {
+        augment.best = true;
+    }
+
+    if let Some(rarity) = rarity {
+        let mut changed = false;
+        for augment in augments.iter().flatten() {
+            changed |= seen.record(augment.id, rarity);
+        }
+        if changed && let Err(e) = seen.save() {
+            log(&format!("Failed to save augment rarities: {}", e));
+        }
+    }
+
+    let input = RerollInput {
+        data: &data,
+        generation,
+        champions: &CHAMPIONS_DATA,
+        champion: &champ_lower,
+        picked,
+        comp_rules,
+        score_rules,
+        preferences,
+        offered: rarity,
+        seen,
+    };
+    let cards = augments.each_ref().map(|a| a.as_ref().and_then(|a| Some((a.id, a.score?))));
+    let advice = reroll.advise(recommender, &input, cards);
+    for (augment, advice) in augments.iter_mut().zip(advice) {
+        if let Some(augment) = augment {
+            augment.reroll = advice;
+        }
+    }
+
     (augments, found_any)
 }
 
-#[cfg(feature = "dev")]
+// Opens the log, or its folder while nothing has been logged yet.
+#[cfg(windows)]
+fn open_log() {
+    let Some(path) = config_file(LOG_FILE) else {
+        return;
+    };
+    let target = match path.parent() {
+        Some(dir) if !path.exists() => {
+            let _ = std::fs::create_dir_all(dir);
+            dir.to_path_buf()
+        }
+        _ => path,
+    };
+    if let Err(e) = std::process::Command::new("explorer").arg(&target).spawn() {
+        eprintln!("Failed to open {}: {}", target.display(), e);
+    }
+}
+
+#[cfg(all(windows, feature = "dev"))]
 #[tokio::main]
 async fn main() {
     let engine = create_engine();
@@ -77,15 +214,27 @@ async fn main() {
     let mut last_poll = Instant::now();
     let mut last_capture = Instant::now();
 
-    let champions_list: Vec<String> = MAYHEM_DATA.champions.keys().cloned().collect();
+    let recommender = Recommender::new(AppConfig::load().weights);
+    let mut reroll = RerollAdvisor::new();
+    let mut seen = SeenRarities::load();
+    let preferences = Preferences::load().and_then(|p| p.check(&MAYHEM_DATA.get()).map(|()| p)).unwrap_or_else(|e| {
+        eprintln!("Failed to load preferences: {}", e);
+        Preferences::default()
+    });
+
+    MAYHEM_DATA.watch();
+
+    let champions_list: Vec<String> = MAYHEM_DATA.get().champions.keys().cloned().collect();
     let mut test_champion_idx = 0usize;
     let mut current_champion = champions_list[test_champion_idx].clone();
 
     println!("Test mode: champion = {}", current_champion);
 
     loop {
-        if let Some(TrayCommand::Exit) = tray.poll() {
-            break;
+        match tray.poll() {
+            Some(TrayCommand::Exit) => break,
+            Some(TrayCommand::OpenLog) => open_log(),
+            None => {}
         }
 
         let now = Instant::now();
@@ -100,14 +249,23 @@ async fn main() {
         if now.duration_since(last_capture) >= CAPTURE_INTERVAL {
             last_capture = now;
 
-            let cards = capture_augment_cards_fullscreen();
+            let (cards, rarity) = capture_augment_cards_fullscreen();
 
             if cards.iter().all(|c| c.is_none()) {
                 continue;
             }
 
             let positions = calculate_card_positions_fullscreen();
-            let (augments, found_any) = process_cards(&engine, &cards, &current_champion);
+            let (augments, found_any) = process_cards(&engine, &cards, &CardContext {
+                champion: &current_champion,
+                data_warning: None,
+                picked: &[],
+                recommender: &recommender,
+                comp_rules: &[],
+                score_rules: &[],
+                preferences: &preferences,
+                rarity,
+            }, &mut reroll, &mut seen);
 
             if found_any {
                 for i in 0..3 {
@@ -127,71 +285,166 @@ async fn main() {
     }
 }
 
-#[cfg(not(feature = "dev"))]
+#[cfg(all(windows, not(feature = "dev")))]
 #[tokio::main]
 async fn main() {
     let engine = create_engine();
-    let client = LiveClient::new().expect("Failed to initialize LiveClient");
+    let config = AppConfig::load();
+    if config.insecure_live_client {
+        log("insecure_live_client is set; the Live Client certificate is not checked");
+    }
     let overlay = Overlay::new().expect("Failed to create overlay");
     let tray = Tray::new().expect("Failed to create tray");
 
+    // Without a client the app stays in the tray with the reason, rather than exiting unseen.
+    let client = match config.tls_mode().and_then(|tls| LiveClient::with_tls(&config.live_client_url, &tls)) {
+        Ok(client) => Some(client),
+        Err(e) => {
+            log(&format!("Failed to initialize LiveClient: {}", e));
+            tray.set_tooltip(&format!("무작위 총력전: 아수라장 어드바이저 - 게임 연결 인증서 오류: {}", e));
+            None
+        }
+    };
+
     let mut last_poll = Instant::now();
+    let mut last_poll_error: Option<String> = None;
+    // Shown in the tooltip; a closed client or a loading game (404) is the normal idle state, not an error.
+    let mut connection_error: Option<String> = None;
     let mut last_capture = Instant::now();
-    let mut current_champion: Option<String> = None;
-    let mut game_active = false;
     let mut overlay_visible = false;
-    let mut last_tooltip_state: Option<bool> = None;
+    let mut last_tooltip = String::new();
+    let mut data_warning: Option<String> = None;
+    let mut tracker = GameTracker::new(&config.augment_levels, load_rules());
+    let recommender = Recommender::new(config.weights.clone());
+    let mut reroll = RerollAdvisor::new();
+    let mut seen = SeenRarities::load();
+    let mut rules_error: Option<String> = None;
+    let mut preferences = Preferences::default();
+    let mut preferences_error: Option<String> = None;
+
+    MAYHEM_DATA.watch();
 
     loop {
-        if let Some(TrayCommand::Exit) = tray.poll() {
-            break;
+        match tray.poll() {
+            Some(TrayCommand::Exit) => break,
+            Some(TrayCommand::OpenLog) => open_log(),
+            None => {}
         }
 
         let now = Instant::now();
 
-        if now.duration_since(last_poll) >= POLL_INTERVAL {
+        if let Some(client) = &client
+            && now.duration_since(last_poll) >= tracker.poll_delay()
+        {
             last_poll = now;
 
-            match client.poll_game_data().await {
-                Some(game_data) => {
-                    if !game_active {
-                        game_active = true;
+            let result = tracker.poll(client).await;
+            match &result.error {
+                // Logged once per distinct error, with the body for schema mismatches.
+                Some(e) => {
+                    let message = e.to_string();
+                    if last_poll_error.as_ref() != Some(&message) {
+                        match e {
+                            LiveClientError::Schema { body, .. } => log(&format!("Live Client: {}\n{}", message, body)),
+                            _ => log(&format!("Live Client: {}", message)),
+                        };
+                        connection_error = (!matches!(e, LiveClientError::NotRunning(_) | LiveClientError::Status(404)))
+                            .then(|| message.clone());
+                        last_poll_error = Some(message);
                     }
+                }
+                None => {
+                    last_poll_error = None;
+                    connection_error = None;
+                }
+            }
 
-                    let is_mayhem = LiveClient::is_mayhem_mode(&game_data);
-                    if last_tooltip_state != Some(is_mayhem) {
-                        last_tooltip_state = Some(is_mayhem);
-                        if is_mayhem {
-                            let champ = current_champion.as_deref().unwrap_or("Unknown");
-                            tray.set_tooltip(&format!("무작위 총력전: 아수라장 어드바이저 - 활성화됨 (챔피언: {})", champ));
-                        } else {
-                            tray.set_tooltip("무작위 총력전: 아수라장 어드바이저");
+            match result.event {
+                PollEvent::Started => {
+                    // Reloaded every game so edits apply without a restart; a broken file keeps the last good rules.
+                    match load_score_rules() {
+                        Ok(rules) => {
+                            tracker.set_score_rules(rules);
+                            rules_error = None;
+                        }
+                        Err(e) => {
+                            log(&format!("Failed to load score rules: {}", e));
+                            rules_error = Some(e.to_string());
                         }
                     }
 
-                    if is_mayhem {
-                        if let Some(champ) = LiveClient::get_my_champion(&game_data) {
-                            if current_champion.as_ref() != Some(&champ) {
-                                current_champion = Some(champ);
-                            }
+                    match Preferences::load().and_then(|p| p.check(&MAYHEM_DATA.get()).map(|()| p)) {
+                        Ok(loaded) => {
+                            preferences = loaded;
+                            preferences_error = None;
+                        }
+                        Err(e) => {
+                            log(&format!("Failed to load preferences: {}", e));
+                            preferences_error = Some(e.to_string());
                         }
-                    } else {
-                        overlay.hide_all();
                     }
+                    reroll = RerollAdvisor::new();
                 }
-                None => {
-                    if game_active {
-                        game_active = false;
-                        current_champion = None;
-                        last_tooltip_state = None;
-                        overlay.hide_all();
-                        tray.set_tooltip("무작위 총력전: 아수라장 어드바이저 - 대기 중");
-                    }
+                PollEvent::Ended => {
+                    data_warning = None;
+                    overlay.hide_all();
+                    overlay_visible = false;
+                }
+                PollEvent::InGame | PollEvent::Idle => {}
+            }
+
+            if let Some(game) = tracker.game() {
+                let warnings = check_metadata(MAYHEM_DATA.get().metadata.as_ref(), game.version.as_deref(), config.max_data_age_days);
+                data_warning = (!warnings.is_empty())
+                    .then(|| warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>().join(" · "));
+
+                if !game.mayhem {
+                    overlay.hide_all();
                 }
             }
+
+            // Rebuilt every poll from the whole state, so one update can't hide another (e.g. an override error).
+            // The tray keeps only the first 128 characters, so errors are only counted; the log has the details.
+            let mut lines = vec![match tracker.game() {
+                None => "무작위 총력전: 아수라장 어드바이저 - 대기 중".to_string(),
+                Some(game) if !game.mayhem => "무작위 총력전: 아수라장 어드바이저".to_string(),
+                Some(game) => {
+                    let champ = game.champion.as_deref().unwrap_or("Unknown");
+                    format!("무작위 총력전: 아수라장 어드바이저 - 활성화됨 (챔피언: {})", champ)
+                }
+            }];
+            let errors = [connection_error.clone(), MAYHEM_DATA.last_error(), rules_error.clone(), preferences_error.clone()];
+            let error_count = errors.iter().flatten().count();
+            if error_count > 0 {
+                lines.push(format!("오류 {}건, 로그 참조", error_count));
+            }
+            if tracker.game().is_some_and(|g| g.mayhem)
+                && let Some(note) = data_warning.clone().or_else(|| MAYHEM_DATA.get().metadata.as_ref().and_then(|m| m.summary()))
+            {
+                lines.push(note);
+            }
+            let tooltip = lines.join("\n");
+            if tooltip != last_tooltip {
+                tray.set_tooltip(&tooltip);
+                last_tooltip = tooltip;
+            }
+        }
+
+        let Some(game) = tracker.game_mut().filter(|g| g.mayhem) else {
+            std::thread::sleep(Duration::from_millis(10));
+            continue;
+        };
+
+        let interval = game.capture_interval();
+        if !game.is_window_open() && overlay_visible {
+            overlay.hide_all();
+            overlay_visible = false;
         }
 
-        if game_active && current_champion.is_some() && now.duration_since(last_capture) >= CAPTURE_INTERVAL {
+        if let Some(interval) = interval
+            && now.duration_since(last_capture) >= interval
+            && let Some(champion) = game.champion.clone()
+        {
             last_capture = now;
 
             if !is_lol_foreground() {
@@ -202,9 +455,10 @@ async fn main() {
                 continue;
             }
 
-            let cards = capture_augment_cards();
+            let (cards, rarity) = capture_augment_cards();
 
             if cards.iter().all(|c| c.is_none()) {
+                game.session.observe(None);
                 continue;
             }
 
@@ -212,7 +466,17 @@ async fn main() {
                 continue;
             };
 
-            let (augments, found_any) = process_cards(&engine, &cards, current_champion.as_ref().unwrap());
+            let (augments, found_any) = process_cards(&engine, &cards, &CardContext {
+                champion: &champion,
+                data_warning: data_warning.as_deref(),
+                picked: &game.session.picked_ids(),
+                recommender: &recommender,
+                comp_rules: &game.comp_rules,
+                score_rules: &game.score_rules,
+                preferences: &preferences,
+                rarity,
+            }, &mut reroll, &mut seen);
+            game.session.observe(found_any.then(|| augments.each_ref().map(|a| a.as_ref().map(|a| a.id))));
 
             if found_any {
                 overlay_visible = true;
@@ -232,3 +496,9 @@ async fn main() {
         std::thread::sleep(Duration::from_millis(10));
     }
 }
+
+// Screen capture, the overlay and the tray are Win32-only; the library and tools still build elsewhere.
+#[cfg(not(windows))]
+fn main() {
+    eprintln!("aram-mayhem-advisor only runs on Windows");
+}
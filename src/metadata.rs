@@ -0,0 +1,145 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataMetadata {
+    // Game client version the stats were collected on, e.g. `15.22`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collected_from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collected_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_games: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DataWarning {
+    PatchMismatch { data: String, game: String },
+    Stale { days: i64 },
+}
+
+impl fmt::Display for DataWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataWarning::PatchMismatch { data, game } => write!(f, "데이터 패치({})가 게임 패치({})와 다릅니다", data, game),
+            DataWarning::Stale { days } => write!(f, "데이터가 {}일 지났습니다", days),
+        }
+    }
+}
+
+impl DataMetadata {
+    pub fn summary(&self) -> Option<String> {
+        let period = match (&self.collected_from, &self.collected_to) {
+            (Some(from), Some(to)) => Some(format!("{} ~ {}", from, to)),
+            (None, Some(to)) => Some(format!("~ {}", to)),
+            (Some(from), None) => Some(format!("{} ~", from)),
+            (None, None) => None,
+        };
+
+        let parts: Vec<String> = [self.patch.as_ref().map(|p| format!("{} 패치", p)), period]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+
+    pub fn check(&self, game_version: Option<&str>, today: i64, max_age_days: i64) -> Vec<DataWarning> {
+        let mut warnings = Vec::new();
+
+        if let (Some(data), Some(game)) = (&self.patch, game_version)
+            && patch_prefix(data).is_some()
+            && patch_prefix(data) != patch_prefix(game)
+        {
+            warnings.push(DataWarning::PatchMismatch {
+                data: data.clone(),
+                game: game.split('.').take(2).collect::<Vec<_>>().join("."),
+            });
+        }
+
+        if let Some(collected) = self.collected_to.as_deref().and_then(parse_date)
+            && today - collected > max_age_days
+        {
+            warnings.push(DataWarning::Stale { days: today - collected });
+        }
+
+        warnings
+    }
+}
+
+pub fn check_metadata(metadata: Option<&DataMetadata>, game_version: Option<&str>, max_age_days: i64) -> Vec<DataWarning> {
+    metadata
+        .map(|metadata| metadata.check(game_version, today(), max_age_days))
+        .unwrap_or_default()
+}
+
+pub fn patch_prefix(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Days since 1970-01-01 for a `YYYY-MM-DD` date; anything else, times included, is rejected.
+pub fn parse_date(date: &str) -> Option<i64> {
+    let bytes = date.as_bytes();
+    let shaped = bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| if i == 4 || i == 7 { *b == b'-' } else { b.is_ascii_digit() });
+    if !shaped {
+        return None;
+    }
+
+    let year: i64 = date[..4].parse().ok()?;
+    let month: i64 = date[5..7].parse().ok()?;
+    let day: i64 = date[8..].parse().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    Some(era * 146_097 + doe - 719_468)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// `YYYY-MM-DD` for days since 1970-01-01; the inverse of `parse_date`.
+pub fn format_date(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+pub fn today() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| (d.as_secs() / 86_400) as i64)
+        .unwrap_or(0)
+}
@@ -1,42 +1,88 @@
+#[cfg(windows)]
 use tao::dpi::{PhysicalPosition, PhysicalSize};
+#[cfg(windows)]
 use tao::event_loop::EventLoopProxy;
+#[cfg(windows)]
 use tao::platform::windows::WindowBuilderExtWindows;
+#[cfg(windows)]
 use tao::window::WindowBuilder;
+#[cfg(windows)]
 use wry::WebViewBuilder;
 
+#[cfg(windows)]
 use crate::capture::get_lol_window;
+use crate::data::ChampionClass;
+use crate::preferences::Mark;
+use crate::reroll::RerollAdvice;
+use crate::scoring::Confidence;
+use crate::stats::StatsLevel;
 
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct ChampionStats {
     pub name: String,
     pub tier: String,
+    pub raw_tier: String,
     pub popularity: String,
     pub games: i32,
+    pub win_rate: Option<f64>,
+    pub win_rate_interval: Option<(f64, f64)>,
+    // `None` when the stats aren't the champion's own.
+    pub confidence: Option<Confidence>,
+    pub level: Option<StatsLevel>,
+    pub class: Option<ChampionClass>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct SynergyBadge {
+    pub name: String,
+    pub tier: String,
+    pub games: i32,
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct AugmentDisplay {
+    pub id: i32,
     pub name: String,
     pub tier: String,
     pub popularity: String,
     pub games: i32,
+    pub win_rate: Option<f64>,
     pub champion: Option<ChampionStats>,
+    pub synergies: Vec<SynergyBadge>,
+    pub score: Option<f64>,
+    pub best: bool,
+    pub reroll: Option<RerollAdvice>,
+    pub mark: Option<Mark>,
+    pub note: Option<String>,
+    pub explanations: Vec<String>,
+    pub data_label: Option<String>,
+    pub data_warning: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub enum OverlayEvent {
-    Update { index: usize, data: Option<AugmentDisplay>, x: i32, y: i32 },
+    Update { index: usize, data: Option<Box<AugmentDisplay>>, x: i32, y: i32 },
     Show(usize),
     Hide(usize),
     HideAll,
 }
 
+/// The script that hands `card` to the overlay page; the JSON travels as a JS string literal, so notes and
+/// rule reasons may hold any character.
+pub fn update_script(card: &AugmentDisplay) -> String {
+    let json = serde_json::to_string(card).unwrap_or_default();
+    format!("window.updateCard({});", serde_json::to_string(&json).unwrap_or_default())
+}
+
+#[cfg(windows)]
 const CARD_HTML: &str = include_str!("../data/overlay.html");
 
+#[cfg(windows)]
 pub struct Overlay {
     proxy: EventLoopProxy<OverlayEvent>,
 }
 
+#[cfg(windows)]
 impl Overlay {
     pub fn new() -> Option<Self> {
         let (tx, rx) = std::sync::mpsc::channel();
@@ -90,9 +136,7 @@ impl Overlay {
                             if index < 3 {
                                 windows[index].set_outer_position(PhysicalPosition::new(x, y));
                                 if let Some(aug) = data {
-                                    let json = serde_json::to_string(&aug).unwrap_or_default();
-                                    let script = format!("window.updateCard('{}');", json.replace('\'', "\\'"));
-                                    let _ = webviews[index].evaluate_script(&script);
+                                    let _ = webviews[index].evaluate_script(&update_script(&aug));
                                 }
                             }
                         }
@@ -130,7 +174,7 @@ impl Overlay {
     pub fn update(&self, index: usize, augment: Option<&AugmentDisplay>, x: i32, y: i32) {
         let _ = self.proxy.send_event(OverlayEvent::Update {
             index,
-            data: augment.cloned(),
+            data: augment.cloned().map(Box::new),
             x,
             y,
         });
@@ -149,9 +193,12 @@ impl Overlay {
     }
 }
 
+#[cfg(windows)]
 const OVERLAY_WIDTH: f32 = 300.0;
+#[cfg(windows)]
 const OVERLAY_HEIGHT: f32 = 320.0;
 
+#[cfg(windows)]
 pub fn calculate_card_positions() -> Option<[(i32, i32); 3]> {
     let game = get_lol_window()?;
 
@@ -183,6 +230,7 @@ pub fn calculate_card_positions() -> Option<[(i32, i32); 3]> {
     ])
 }
 
+#[cfg(windows)]
 pub fn calculate_card_positions_fullscreen() -> [(i32, i32); 3] {
     use crate::capture::get_screen_size;
 
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::config_file;
+use crate::data::{Augment, MayhemData};
+
+pub const PREFERENCES_FILE: &str = "preferences.json";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mark {
+    Favorite,
+    Avoid,
+}
+
+impl Mark {
+    pub fn sign(self) -> f64 {
+        match self {
+            Mark::Favorite => 1.0,
+            Mark::Avoid => -1.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark: Option<Mark>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl Preference {
+    fn is_empty(&self) -> bool {
+        self.mark.is_none() && self.note.is_none()
+    }
+}
+
+// Keys are an augment id or its name in any language. Champion entries win over global ones, field by field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub augments: HashMap<String, Preference>,
+    pub champions: HashMap<String, HashMap<String, Preference>>,
+}
+
+#[derive(Debug)]
+pub enum PreferencesError {
+    Io(PathBuf, std::io::Error),
+    Parse(PathBuf, serde_json::Error),
+    UnknownAugment(String),
+}
+
+impl fmt::Display for PreferencesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreferencesError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            PreferencesError::Parse(path, e) => write!(f, "{}: {}", path.display(), e),
+            PreferencesError::UnknownAugment(key) => write!(f, "unknown augment \"{}\"", key),
+        }
+    }
+}
+
+impl std::error::Error for PreferencesError {}
+
+impl Preferences {
+    pub fn get(&self, champion: &str, augment: &Augment) -> Option<Preference> {
+        let global = find(&self.augments, augment);
+        let local = self.champions.get(&champion.to_lowercase()).and_then(|m| find(m, augment));
+
+        let preference = Preference {
+            mark: local.and_then(|p| p.mark).or_else(|| global.and_then(|p| p.mark)),
+            note: local.and_then(|p| p.note.clone()).or_else(|| global.and_then(|p| p.note.clone())),
+        };
+        (!preference.is_empty()).then_some(preference)
+    }
+
+    /// Fails on the first key that names no augment in `data`, so a typo doesn't silently do nothing.
+    pub fn check(&self, data: &MayhemData) -> Result<(), PreferencesError> {
+        let keys = self.augments.keys().chain(self.champions.values().flat_map(|m| m.keys()));
+        for key in keys {
+            if !data.augments.iter().any(|a| matches(key, a)) {
+                return Err(PreferencesError::UnknownAugment(key.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, PreferencesError> {
+        let text = std::fs::read_to_string(path).map_err(|e| PreferencesError::Io(path.to_path_buf(), e))?;
+        serde_json::from_str(&text).map_err(|e| PreferencesError::Parse(path.to_path_buf(), e))
+    }
+
+    /// `preferences.json` from the config directory; a missing file means no preferences.
+    pub fn load() -> Result<Self, PreferencesError> {
+        match config_file(PREFERENCES_FILE).filter(|p| p.exists()) {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+fn matches(key: &str, augment: &Augment) -> bool {
+    let key = key.trim();
+    key.parse() == Ok(augment.id) || augment.name.0.values().any(|name| name.to_lowercase() == key.to_lowercase())
+}
+
+fn find<'a>(entries: &'a HashMap<String, Preference>, augment: &Augment) -> Option<&'a Preference> {
+    entries.get(&augment.id.to_string()).or_else(|| entries.iter().find(|(key, _)| matches(key, augment)).map(|(_, preference)| preference))
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use image::{DynamicImage, GenericImageView};
+
+use crate::config::config_file;
+use crate::data::Rarity;
+
+pub const RARITIES_FILE: &str = "rarities.json";
+
+// Mean color of each rarity's outer card frame, measured on the samples in `testdata/augments`.
+const FRAME_COLORS: [(Rarity, [f64; 3]); 3] = [
+    (Rarity::Silver, [116.0, 117.0, 116.0]),
+    (Rarity::Gold, [155.0, 128.0, 104.0]),
+    (Rarity::Prismatic, [172.0, 173.0, 228.0]),
+];
+// Farther than this from every frame color and the strip isn't a card frame at all.
+const MAX_COLOR_DISTANCE: f64 = 40.0;
+
+/// The rarity whose frame color is closest to the mean color of `frame`, a strip of a card's border.
+pub fn frame_rarity(frame: &DynamicImage) -> Option<Rarity> {
+    let pixels = frame.width() as f64 * frame.height() as f64;
+    if pixels == 0.0 {
+        return None;
+    }
+
+    let mut sum = [0.0; 3];
+    for (_, _, pixel) in frame.pixels() {
+        for (total, channel) in sum.iter_mut().zip(pixel.0) {
+            *total += channel as f64;
+        }
+    }
+    let mean = sum.map(|total| total / pixels);
+
+    FRAME_COLORS
+        .iter()
+        .map(|(rarity, color)| {
+            let distance = mean.iter().zip(color).map(|(a, b)| (a - b).powi(2)).sum::<f64>().sqrt();
+            (*rarity, distance)
+        })
+        .filter(|(_, distance)| *distance <= MAX_COLOR_DISTANCE)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(rarity, _)| rarity)
+}
+
+/// The rarity of an offer; the cards in one offer share it, so at least two frames must agree.
+pub fn offered_rarity(frames: &[DynamicImage]) -> Option<Rarity> {
+    let read: Vec<Rarity> = frames.iter().filter_map(frame_rarity).collect();
+    Rarity::ALL.into_iter().find(|rarity| read.iter().filter(|r| *r == rarity).count() >= 2)
+}
+
+/// Augment rarities read off offered cards, kept in the config directory across games so reroll pools fill in
+/// for augments the data has no rarity for.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SeenRarities {
+    rarities: BTreeMap<i32, Rarity>,
+}
+
+impl SeenRarities {
+    pub const fn new() -> Self {
+        Self { rarities: BTreeMap::new() }
+    }
+
+    pub fn get(&self, augment_id: i32) -> Option<Rarity> {
+        self.rarities.get(&augment_id).copied()
+    }
+
+    /// Returns whether anything changed, i.e. whether saving is worthwhile.
+    pub fn record(&mut self, augment_id: i32, rarity: Rarity) -> bool {
+        self.rarities.insert(augment_id, rarity) != Some(rarity)
+    }
+
+    pub fn load_from(path: &Path) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let rarities = serde_json::from_str(&text).map_err(io::Error::other)?;
+        Ok(Self { rarities })
+    }
+
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&self.rarities).map_err(io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = config_file(RARITIES_FILE).ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+        self.save_to(&path)
+    }
+
+    /// `rarities.json` from the config directory; a missing or broken file starts over empty.
+    pub fn load() -> Self {
+        config_file(RARITIES_FILE).and_then(|path| Self::load_from(&path).ok()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::{Augment, Synergy};
+use crate::explain::Explanation;
+use crate::preferences::Preference;
+use crate::rules::ScoreRule;
+use crate::scoring::{tier_value, PRIOR_GAMES};
+use crate::stats::{ResolvedStats, StatsLevel};
+use crate::teamcomp::CompRule;
+
+// Score a card with no tier at all gets, between `B` and `C`.
+const NEUTRAL_TIER: f64 = 2.5;
+// Synergy tiers above this add to the score. `strong_synergies` only passes `A` and up, so it never subtracts.
+const SYNERGY_BASELINE: f64 = 3.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Weights {
+    pub global_tier: f64,
+    pub champion_tier: f64,
+    // Champion games that count as much as the global tier; fewer games lean on the global tier.
+    pub sample_games: f64,
+    // Multiplier on the champion weight when the stats come from the champion's class.
+    pub class_fallback: f64,
+    pub synergy: f64,
+    pub team_comp: f64,
+    // Added for favorites and subtracted for avoided augments.
+    pub preference: f64,
+    // How much a reroll's expected score must beat the current card's to be suggested.
+    pub reroll_margin: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            global_tier: 1.0,
+            champion_tier: 1.5,
+            sample_games: PRIOR_GAMES,
+            class_fallback: 0.5,
+            synergy: 0.5,
+            team_comp: 1.0,
+            preference: 1.0,
+            reroll_margin: 0.25,
+        }
+    }
+}
+
+pub struct CardInput<'a> {
+    pub augment: &'a Augment,
+    pub stats: Option<&'a ResolvedStats>,
+    pub synergies: &'a [&'a Synergy],
+    // Team composition rules that fired this game; only the ones listing the card's augment count.
+    pub comp_rules: &'a [CompRule],
+    // User rules whose game-wide filters hold; the card filters are checked here.
+    pub score_rules: &'a [ScoreRule],
+    pub preference: Option<&'a Preference>,
+}
+
+impl<'a> CardInput<'a> {
+    /// A card with no stats, synergies, rules or preference; the `with_*` setters fill those in.
+    pub fn new(augment: &'a Augment) -> Self {
+        Self { augment, stats: None, synergies: &[], comp_rules: &[], score_rules: &[], preference: None }
+    }
+
+    pub fn with_stats(self, stats: Option<&'a ResolvedStats>) -> Self {
+        Self { stats, ..self }
+    }
+
+    pub fn with_synergies(self, synergies: &'a [&'a Synergy]) -> Self {
+        Self { synergies, ..self }
+    }
+
+    pub fn with_comp_rules(self, comp_rules: &'a [CompRule]) -> Self {
+        Self { comp_rules, ..self }
+    }
+
+    pub fn with_score_rules(self, score_rules: &'a [ScoreRule]) -> Self {
+        Self { score_rules, ..self }
+    }
+
+    pub fn with_preference(self, preference: Option<&'a Preference>) -> Self {
+        Self { preference, ..self }
+    }
+
+    pub fn matching_rules(&self) -> impl Iterator<Item = &CompRule> {
+        self.comp_rules.iter().filter(|r| r.augments.contains(&self.augment.id))
+    }
+
+    pub fn matching_score_rules(&self) -> impl Iterator<Item = &ScoreRule> {
+        self.score_rules.iter().filter(|r| r.applies_to(self.augment))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CardScore {
+    pub score: f64,
+    pub tier: f64,
+    pub synergy: f64,
+    pub team: f64,
+    pub custom: f64,
+    pub preference: f64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Recommender {
+    pub weights: Weights,
+}
+
+impl Recommender {
+    pub fn new(weights: Weights) -> Self {
+        Self { weights }
+    }
+
+    pub fn score(&self, card: &CardInput) -> CardScore {
+        let w = &self.weights;
+
+        let mut total = 0.0;
+        let mut weight = 0.0;
+
+        if let Some(global) = card.augment.tier.as_deref().and_then(tier_value) {
+            total += global * w.global_tier;
+            weight += w.global_tier;
+        }
+
+        if let Some(stats) = card.stats.filter(|s| s.level != StatsLevel::Global)
+            && let Some(champion) = tier_value(&stats.stats.tier)
+        {
+            let games = stats.stats.games.max(0) as f64;
+            let mut champion_weight = w.champion_tier * games / (games + w.sample_games.max(1.0));
+            if stats.level == StatsLevel::Class {
+                champion_weight *= w.class_fallback;
+            }
+            total += champion * champion_weight;
+            weight += champion_weight;
+        }
+
+        let tier = if weight > 0.0 { total / weight } else { NEUTRAL_TIER };
+
+        let synergy = w.synergy
+            * card
+                .synergies
+                .iter()
+                .filter_map(|s| tier_value(&s.tier))
+                .map(|value| value - SYNERGY_BASELINE)
+                .sum::<f64>();
+
+        let team = w.team_comp * card.matching_rules().map(|r| r.adjust).sum::<f64>();
+
+        let custom = card.matching_score_rules().map(|r| r.adjust).sum::<f64>();
+
+        let preference = w.preference * card.preference.and_then(|p| p.mark).map_or(0.0, |m| m.sign());
+
+        CardScore {
+            score: tier + synergy + team + custom + preference,
+            tier,
+            synergy,
+            team,
+            custom,
+            preference,
+        }
+    }
+
+    pub fn explain(&self, card: &CardInput) -> Vec<Explanation> {
+        let mut lines = Vec::new();
+
+        match card.stats {
+            Some(stats) if stats.level == StatsLevel::Champion => {
+                if (stats.stats.games as f64) < self.weights.sample_games {
+                    lines.push(Explanation::LowSample { games: stats.stats.games });
+                } else {
+                    lines.push(Explanation::ChampionTier { tier: stats.stats.tier.clone(), games: stats.stats.games });
+                }
+            }
+            Some(ResolvedStats { level: StatsLevel::Class, class: Some(class), stats }) => {
+                lines.push(Explanation::ClassTier { class: *class, tier: stats.tier.clone(), games: stats.games });
+            }
+            _ => lines.push(Explanation::NoChampionData),
+        }
+
+        if let Some(tier) = &card.augment.tier
+            && !matches!(lines[0], Explanation::ChampionTier { .. })
+        {
+            lines.push(Explanation::GlobalTier { tier: tier.clone() });
+        }
+
+        for synergy in card.synergies {
+            if let Some(partner) = synergy.partner(card.augment.id) {
+                lines.push(Explanation::Synergy { partner, tier: synergy.tier.clone() });
+            }
+        }
+
+        for rule in card.matching_rules() {
+            lines.push(Explanation::TeamComp { rule: rule.name.clone(), reason: rule.reason.clone() });
+        }
+
+        for rule in card.matching_score_rules() {
+            lines.push(Explanation::Rule { rule: rule.name.clone(), reason: rule.reason.clone() });
+        }
+
+        if let Some(rarity) = card.augment.rarity {
+            lines.push(Explanation::Rarity(rarity));
+        }
+
+        lines
+    }
+
+    pub fn rank(&self, cards: &[Option<CardInput>; 3]) -> [Option<CardScore>; 3] {
+        cards.each_ref().map(|card| card.as_ref().map(|c| self.score(c)))
+    }
+
+    /// Slot of the highest score; ties go to the leftmost card.
+    pub fn best(scores: &[Option<CardScore>; 3]) -> Option<usize> {
+        scores
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| s.as_ref().map(|s| (i, s.score)))
+            .fold(None, |best: Option<(usize, f64)>, (i, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((i, score)),
+            })
+            .map(|(i, _)| i)
+    }
+}
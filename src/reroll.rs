@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::data::{ChampionInfo, MayhemData, Rarity};
+use crate::preferences::Preferences;
+use crate::rarity::SeenRarities;
+use crate::recommend::{CardInput, Recommender};
+use crate::rules::ScoreRule;
+use crate::stats::{resolve_stats, strong_synergies};
+use crate::teamcomp::CompRule;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct RerollAdvice {
+    // Mean score of the augments a reroll of this slot could land on.
+    pub expected: f64,
+    pub better: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct PoolKey {
+    generation: u64,
+    champion: String,
+    picked: Vec<i32>,
+    rules: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PoolEntry {
+    id: i32,
+    rarity: Option<Rarity>,
+    score: f64,
+}
+
+pub struct RerollInput<'a> {
+    pub data: &'a MayhemData,
+    // From `DataStore::snapshot`; the pool is rebuilt when it changes.
+    pub generation: u64,
+    pub champions: &'a HashMap<String, ChampionInfo>,
+    pub champion: &'a str,
+    pub picked: &'a [i32],
+    pub comp_rules: &'a [CompRule],
+    pub score_rules: &'a [ScoreRule],
+    // Not part of the cache key; start a new advisor after reloading them.
+    pub preferences: &'a Preferences,
+    // Read off the offered cards' frames; wins over the data for the cards on screen.
+    pub offered: Option<Rarity>,
+    // Fills in rarities the data lacks, for the cards and the pool alike.
+    pub seen: &'a SeenRarities,
+}
+
+// Scoring every augment is too slow to redo on each capture, so the pool is kept until its inputs change.
+#[derive(Default)]
+pub struct RerollAdvisor {
+    pool: Option<(PoolKey, Vec<PoolEntry>)>,
+}
+
+impl RerollAdvisor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `cards` holds each recognized slot's augment id and recommender score.
+    pub fn advise(
+        &mut self,
+        recommender: &Recommender,
+        input: &RerollInput,
+        cards: [Option<(i32, f64)>; 3],
+    ) -> [Option<RerollAdvice>; 3] {
+        let RerollInput { data, picked, offered, seen, .. } = *input;
+        let pool = self.pool(recommender, input);
+        let shown: Vec<i32> = cards.iter().flatten().map(|(id, _)| *id).collect();
+        let margin = recommender.weights.reroll_margin;
+
+        cards.map(|card| {
+            let (id, score) = card?;
+            // Without the card's rarity the reroll pool is unknown, so no advice beats a wrong one.
+            let rarity = offered
+                .or_else(|| data.augments.iter().find(|a| a.id == id).and_then(|a| a.rarity))
+                .or_else(|| seen.get(id))?;
+
+            // Rerolls stay within the card's rarity and never repeat an offered or picked augment.
+            let (sum, count) = pool
+                .iter()
+                .filter(|e| e.rarity.or_else(|| seen.get(e.id)) == Some(rarity))
+                .filter(|e| !shown.contains(&e.id) && !picked.contains(&e.id))
+                .fold((0.0, 0usize), |(sum, count), e| (sum + e.score, count + 1));
+
+            (count > 0).then(|| {
+                let expected = sum / count as f64;
+                RerollAdvice { expected, better: expected > score + margin }
+            })
+        })
+    }
+
+    fn pool(&mut self, recommender: &Recommender, input: &RerollInput) -> &[PoolEntry] {
+        let RerollInput { data, generation, champions, champion, picked, comp_rules, score_rules, preferences, .. } = *input;
+        let key = PoolKey {
+            generation,
+            champion: champion.to_string(),
+            picked: picked.to_vec(),
+            rules: comp_rules.iter().map(|r| &r.name).chain(score_rules.iter().map(|r| &r.name)).cloned().collect(),
+        };
+
+        if self.pool.as_ref().is_none_or(|(cached, _)| *cached != key) {
+            let entries = data
+                .augments
+                .iter()
+                .filter(|a| a.tier.is_some())
+                .map(|augment| {
+                    let stats = resolve_stats(data, champions, champion, augment);
+                    let synergies = strong_synergies(data, augment.id, picked);
+                    let preference = preferences.get(champion, augment);
+                    let card = CardInput::new(augment)
+                        .with_stats(stats.as_ref())
+                        .with_synergies(&synergies)
+                        .with_comp_rules(comp_rules)
+                        .with_score_rules(score_rules)
+                        .with_preference(preference.as_ref());
+                    let score = recommender.score(&card);
+                    PoolEntry { id: augment.id, rarity: augment.rarity, score: score.score }
+                })
+                .collect();
+            self.pool = Some((key, entries));
+        }
+
+        self.pool.as_ref().map(|(_, entries)| entries.as_slice()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::data::{ChampionInfo, CHAMPIONS_DATA};
+
+const RAW_NAME_PREFIX: &str = "game_character_displayname_";
+
+pub static CHAMPION_RESOLVER: LazyLock<ChampionResolver> = LazyLock::new(|| ChampionResolver::new(&CHAMPIONS_DATA));
+
+// Maps data keys, Riot internal names, numeric keys and localized names to the `champions.json` key.
+pub struct ChampionResolver {
+    aliases: HashMap<String, String>,
+}
+
+impl ChampionResolver {
+    pub fn new(champions: &HashMap<String, ChampionInfo>) -> Self {
+        let mut ids: Vec<&String> = champions.keys().collect();
+        ids.sort();
+
+        let mut aliases = HashMap::new();
+
+        // Keys win over names so a localized name can never shadow another champion's id.
+        for id in &ids {
+            aliases.insert(normalize(id), (*id).clone());
+            if let Some(key) = champions[*id].key {
+                aliases.insert(key.to_string(), (*id).clone());
+            }
+        }
+
+        for id in &ids {
+            for name in champions[*id].name.0.values() {
+                let alias = normalize(name);
+                if !alias.is_empty() {
+                    aliases.entry(alias).or_insert_with(|| (*id).clone());
+                }
+            }
+        }
+
+        Self { aliases }
+    }
+
+    pub fn resolve(&self, input: &str) -> Option<&str> {
+        let input = input.trim();
+        let input = match input.get(..RAW_NAME_PREFIX.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(RAW_NAME_PREFIX) => &input[RAW_NAME_PREFIX.len()..],
+            _ => input,
+        };
+
+        self.aliases.get(&normalize(input)).map(String::as_str)
+    }
+}
+
+// Lowercases and drops spaces and punctuation, so "Nunu & Willump", "Kai'Sa" and "Dr. Mundo" line up with their keys.
+pub fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
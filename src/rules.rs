@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::config::config_file;
+use crate::data::{Augment, ChampionInfo, Name};
+use crate::teamcomp::{Condition, RulesError, TeamComp};
+
+pub const SCORE_RULES_FILE: &str = "score_rules.json";
+
+// Every filter that is set must hold; list filters match any entry.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleCondition {
+    pub champions: Vec<String>,
+    pub augments: Vec<i32>,
+    // Overall tier of the offered augment.
+    pub tiers: Vec<String>,
+    // Augment ids picked earlier this game.
+    pub picked: Vec<i32>,
+    pub team: Vec<Condition>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScoreRule {
+    pub name: String,
+    #[serde(default)]
+    pub when: RuleCondition,
+    // Added to the card score as is.
+    pub adjust: f64,
+    #[serde(default)]
+    pub reason: Name,
+}
+
+pub struct RuleContext<'a> {
+    pub champion: &'a str,
+    pub picked: &'a [i32],
+    pub comp: Option<&'a TeamComp>,
+    pub champions: &'a HashMap<String, ChampionInfo>,
+}
+
+impl ScoreRule {
+    /// Whether the game-wide filters hold; the card filters are checked by `applies_to`.
+    pub fn is_active(&self, ctx: &RuleContext) -> bool {
+        let when = &self.when;
+        let champion_ok = when.champions.is_empty() || when.champions.iter().any(|c| c.eq_ignore_ascii_case(ctx.champion));
+        let picked_ok = when.picked.is_empty() || when.picked.iter().any(|id| ctx.picked.contains(id));
+        let team_ok = when.team.is_empty()
+            || ctx.comp.is_some_and(|comp| when.team.iter().all(|c| c.matches(comp, ctx.champions)));
+
+        champion_ok && picked_ok && team_ok
+    }
+
+    pub fn applies_to(&self, augment: &Augment) -> bool {
+        let when = &self.when;
+        let augment_ok = when.augments.is_empty() || when.augments.contains(&augment.id);
+        let tier_ok = when.tiers.is_empty()
+            || augment.tier.as_deref().is_some_and(|t| when.tiers.iter().any(|w| w.eq_ignore_ascii_case(t)));
+
+        augment_ok && tier_ok
+    }
+}
+
+pub fn parse_score_rules(source: &str, text: &str) -> Result<Vec<ScoreRule>, RulesError> {
+    serde_json::from_str(text).map_err(|e| RulesError::Parse(source.to_string(), e))
+}
+
+/// `score_rules.json` from the config directory; a missing file means no rules.
+pub fn load_score_rules() -> Result<Vec<ScoreRule>, RulesError> {
+    let Some(path) = config_file(SCORE_RULES_FILE).filter(|p| p.exists()) else {
+        return Ok(Vec::new());
+    };
+
+    let text = std::fs::read_to_string(&path).map_err(|e| RulesError::Io(path.clone(), e))?;
+    parse_score_rules(&path.display().to_string(), &text)
+}
+
+pub fn active_score_rules(rules: &[ScoreRule], ctx: &RuleContext) -> Vec<ScoreRule> {
+    rules.iter().filter(|r| r.is_active(ctx)).cloned().collect()
+}
@@ -0,0 +1,103 @@
+use serde::Serialize;
+
+use crate::data::{Augment, Champion, TIERS};
+
+// Games of "virtual" global-tier evidence mixed into every champion-specific tier.
+pub const PRIOR_GAMES: f64 = 100.0;
+pub const Z_95: f64 = 1.96;
+
+const LOW_CONFIDENCE_GAMES: i32 = 100;
+const HIGH_CONFIDENCE_GAMES: i32 = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl Confidence {
+    pub fn from_games(games: i32) -> Self {
+        if games < LOW_CONFIDENCE_GAMES {
+            Confidence::Low
+        } else if games < HIGH_CONFIDENCE_GAMES {
+            Confidence::Medium
+        } else {
+            Confidence::High
+        }
+    }
+}
+
+/// `S+` maps to 6.0 and `D` to 1.0.
+pub fn tier_value(tier: &str) -> Option<f64> {
+    TIERS
+        .iter()
+        .position(|t| t.eq_ignore_ascii_case(tier.trim()))
+        .map(|i| (TIERS.len() - i) as f64)
+}
+
+pub fn tier_from_value(value: f64) -> &'static str {
+    let index = (TIERS.len() as f64 - value.round()).clamp(0.0, (TIERS.len() - 1) as f64);
+    TIERS[index as usize]
+}
+
+pub fn wilson_interval(win_rate: f64, games: i32, z: f64) -> (f64, f64) {
+    if games <= 0 {
+        return (0.0, 1.0);
+    }
+
+    let n = games as f64;
+    let p = win_rate.clamp(0.0, 1.0);
+    let z2 = z * z;
+    let denominator = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denominator;
+    let margin = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denominator;
+
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+pub fn shrink(value: f64, games: i32, prior: f64, prior_games: f64) -> f64 {
+    let games = games.max(0) as f64;
+    (value * games + prior * prior_games) / (games + prior_games)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChampionScore {
+    pub tier: String,
+    pub raw_tier: String,
+    pub score: f64,
+    pub confidence: Confidence,
+    pub win_rate: Option<f64>,
+    pub win_rate_interval: Option<(f64, f64)>,
+}
+
+/// Pulls a champion-specific tier toward the augment's global tier in proportion
+/// to how few games back it. Without a global tier the raw tier is kept.
+pub fn score_champion(champion: &Champion, augment: &Augment) -> ChampionScore {
+    let confidence = Confidence::from_games(champion.games);
+    let raw = tier_value(&champion.tier);
+    let global = augment.tier.as_deref().and_then(tier_value);
+
+    let score = match (raw, global) {
+        (Some(raw), Some(global)) => shrink(raw, champion.games, global, PRIOR_GAMES),
+        (Some(raw), None) => raw,
+        (None, Some(global)) => global,
+        (None, None) => 0.0,
+    };
+
+    let tier = if raw.is_some() || global.is_some() {
+        tier_from_value(score).to_string()
+    } else {
+        champion.tier.clone()
+    };
+
+    ChampionScore {
+        tier,
+        raw_tier: champion.tier.clone(),
+        score,
+        confidence,
+        win_rate: champion.win_rate,
+        win_rate_interval: champion.win_rate.map(|wr| wilson_interval(wr, champion.games, Z_95)),
+    }
+}
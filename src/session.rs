@@ -0,0 +1,124 @@
+// Frames (at `CAPTURE_INTERVAL`) the same cards must be read before they count as offered.
+const STABLE_FRAMES: u32 = 3;
+// Frames without cards before an open augment panel counts as closed.
+const CLOSE_FRAMES: u32 = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Pick {
+    Known(i32),
+    // Narrowed down as the remaining cards show up on later screens.
+    Ambiguous(Vec<i32>),
+}
+
+impl Pick {
+    pub fn id(&self) -> Option<i32> {
+        match self {
+            Pick::Known(id) => Some(*id),
+            Pick::Ambiguous(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct GameSession {
+    picks: Vec<Pick>,
+    seen: Option<[Option<i32>; 3]>,
+    seen_frames: u32,
+    offered: Option<Vec<i32>>,
+    // The offer behind the last recorded pick, in case the panel only flickered away.
+    last_closed: Option<Vec<i32>>,
+    empty_frames: u32,
+}
+
+impl GameSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn picks(&self) -> &[Pick] {
+        &self.picks
+    }
+
+    pub fn picked_ids(&self) -> Vec<i32> {
+        self.picks.iter().filter_map(Pick::id).collect()
+    }
+
+    pub fn is_panel_open(&self) -> bool {
+        self.offered.is_some()
+    }
+
+    /// Whether the last capture read any cards, before they are stable enough to count as offered.
+    pub fn sees_cards(&self) -> bool {
+        self.seen.is_some()
+    }
+
+    /// Feeds one capture: the augment ids read from the three cards, or `None`
+    /// when no augment panel is on screen. Returns a pick once the panel closes.
+    pub fn observe(&mut self, cards: Option<[Option<i32>; 3]>) -> Option<Pick> {
+        let Some(cards) = cards.filter(|c| c.iter().any(Option::is_some)) else {
+            self.seen = None;
+            self.seen_frames = 0;
+            self.empty_frames += 1;
+            if self.empty_frames >= CLOSE_FRAMES {
+                return self.close_panel();
+            }
+            return None;
+        };
+
+        self.empty_frames = 0;
+        if self.seen == Some(cards) {
+            self.seen_frames += 1;
+        } else {
+            self.seen = Some(cards);
+            self.seen_frames = 1;
+        }
+
+        if self.seen_frames == STABLE_FRAMES {
+            let mut offered: Vec<i32> = cards.iter().flatten().copied().collect();
+            offered.sort_unstable();
+            if self.offered.is_none() && self.last_closed.as_ref() == Some(&offered) {
+                // The same cards came back, so the panel never closed and nothing was picked.
+                self.picks.pop();
+                self.last_closed = None;
+                self.offered = Some(offered);
+                return None;
+            }
+
+            self.last_closed = None;
+            self.eliminate(&offered);
+            // Rerolls replace cards while the panel stays open, so the latest stable set wins.
+            self.offered = Some(offered);
+        }
+
+        None
+    }
+
+    fn close_panel(&mut self) -> Option<Pick> {
+        let offered = self.offered.take()?;
+        let picked_before = self.picked_ids();
+        let candidates: Vec<i32> = offered.iter().copied().filter(|id| !picked_before.contains(id)).collect();
+
+        let pick = match candidates.as_slice() {
+            [] => return None,
+            [id] => Pick::Known(*id),
+            _ => Pick::Ambiguous(candidates),
+        };
+        self.picks.push(pick.clone());
+        self.last_closed = Some(offered);
+        Some(pick)
+    }
+
+    // A picked augment is never offered again, so a candidate shown on a later screen was not the one taken.
+    fn eliminate(&mut self, offered: &[i32]) {
+        for pick in &mut self.picks {
+            if let Pick::Ambiguous(candidates) = pick {
+                candidates.retain(|id| !offered.contains(id));
+                if let [id] = candidates.as_slice() {
+                    *pick = Pick::Known(*id);
+                }
+            }
+        }
+        // Every candidate came back, so the pick wasn't one of them after all.
+        self.picks.retain(|pick| !matches!(pick, Pick::Ambiguous(candidates) if candidates.is_empty()));
+    }
+}
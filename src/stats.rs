@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::data::{parse_percent, Augment, Champion, ChampionClass, ChampionInfo, MayhemData, Synergy};
+use crate::scoring::{tier_from_value, tier_value, Confidence};
+
+// Pairs below this tier are not worth a badge.
+const SYNERGY_MIN_TIER: f64 = 4.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsLevel {
+    Champion,
+    Class,
+    Global,
+}
+
+#[derive(Clone, Debug)]
+pub struct ResolvedStats {
+    pub level: StatsLevel,
+    pub class: Option<ChampionClass>,
+    pub stats: Champion,
+}
+
+impl ResolvedStats {
+    /// How far to trust the stats for this champion. Class stats pool other champions, so they
+    /// never rate above `Low` however many games they add up to; global stats get no rating.
+    pub fn confidence(&self) -> Option<Confidence> {
+        match self.level {
+            StatsLevel::Champion => Some(Confidence::from_games(self.stats.games)),
+            StatsLevel::Class => Some(Confidence::Low),
+            StatsLevel::Global => None,
+        }
+    }
+}
+
+/// Champion-specific stats when the data has them, otherwise the games-weighted
+/// stats of champions sharing the primary class, otherwise the augment's own.
+pub fn resolve_stats(
+    data: &MayhemData,
+    champions: &HashMap<String, ChampionInfo>,
+    champion: &str,
+    augment: &Augment,
+) -> Option<ResolvedStats> {
+    if let Some(stats) = data.champions.get(champion).and_then(|entries| entries.iter().find(|c| c.id == augment.id)) {
+        return Some(ResolvedStats { level: StatsLevel::Champion, class: None, stats: stats.clone() });
+    }
+
+    let class = champions.get(champion).and_then(ChampionInfo::primary_class);
+    if let Some(class) = class
+        && let Some(stats) = class_stats(data, champions, class, augment.id)
+    {
+        return Some(ResolvedStats { level: StatsLevel::Class, class: Some(class), stats });
+    }
+
+    global_stats(augment).map(|stats| ResolvedStats { level: StatsLevel::Global, class, stats })
+}
+
+pub fn class_stats(
+    data: &MayhemData,
+    champions: &HashMap<String, ChampionInfo>,
+    class: ChampionClass,
+    augment_id: i32,
+) -> Option<Champion> {
+    let mut games = 0i64;
+    let mut tier_sum = 0.0;
+    let mut tier_games = 0i64;
+    let mut popularity_sum = 0.0;
+    let mut popularity_games = 0i64;
+    let mut wins = 0.0;
+    let mut win_rate_games = 0i64;
+
+    for (key, entries) in &data.champions {
+        if champions.get(key).and_then(ChampionInfo::primary_class) != Some(class) {
+            continue;
+        }
+        let Some(entry) = entries.iter().find(|c| c.id == augment_id) else {
+            continue;
+        };
+
+        let weight = entry.games.max(0) as i64;
+        games += weight;
+        if let Some(value) = tier_value(&entry.tier) {
+            tier_sum += value * weight as f64;
+            tier_games += weight;
+        }
+        if let Some(popularity) = parse_percent(&entry.popularity) {
+            popularity_sum += popularity * weight as f64;
+            popularity_games += weight;
+        }
+        if let Some(win_rate) = entry.win_rate {
+            wins += win_rate * weight as f64;
+            win_rate_games += weight;
+        }
+    }
+
+    if games == 0 || tier_games == 0 {
+        return None;
+    }
+
+    Some(Champion {
+        id: augment_id,
+        tier: tier_from_value(tier_sum / tier_games as f64).to_string(),
+        popularity: if popularity_games > 0 {
+            format!("{:.2}%", popularity_sum / popularity_games as f64)
+        } else {
+            "-".to_string()
+        },
+        games: games.min(i32::MAX as i64) as i32,
+        win_rate: (win_rate_games > 0).then(|| wins / win_rate_games as f64),
+    })
+}
+
+pub fn global_stats(augment: &Augment) -> Option<Champion> {
+    let tier = augment.tier.clone()?;
+
+    Some(Champion {
+        id: augment.id,
+        tier,
+        popularity: augment.popularity.clone().unwrap_or_else(|| "-".to_string()),
+        games: augment.games.unwrap_or(0),
+        win_rate: augment.win_rate,
+    })
+}
+
+/// Synergies between `augment_id` and already picked augments rated `A` or better, best first.
+pub fn strong_synergies<'a>(data: &'a MayhemData, augment_id: i32, picked: &[i32]) -> Vec<&'a Synergy> {
+    let mut synergies: Vec<(&Synergy, f64)> = picked
+        .iter()
+        .filter(|&&id| id != augment_id)
+        .filter_map(|&id| data.synergy(augment_id, id))
+        .filter_map(|s| tier_value(&s.tier).map(|value| (s, value)))
+        .filter(|(_, value)| *value >= SYNERGY_MIN_TIER)
+        .collect();
+
+    synergies.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.0.games.cmp(&a.0.games)));
+    synergies.into_iter().map(|(s, _)| s).collect()
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+
+use serde::Deserialize;
+
+use crate::data::{Augment, Champion, MayhemData, Name, Rarity, Synergy};
+use crate::log::log;
+use crate::metadata::DataMetadata;
+
+pub const OVERRIDE_FILE: &str = "overrides.json";
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DataOverrides {
+    #[serde(default)]
+    pub metadata: Option<DataMetadata>,
+    #[serde(default)]
+    pub augments: Vec<AugmentOverride>,
+    #[serde(default)]
+    pub champions: HashMap<String, Vec<Champion>>,
+    #[serde(default)]
+    pub synergies: Vec<Synergy>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AugmentOverride {
+    pub id: i32,
+    #[serde(default)]
+    pub name: Option<Name>,
+    #[serde(default)]
+    pub tier: Option<String>,
+    #[serde(default)]
+    pub popularity: Option<String>,
+    #[serde(default)]
+    pub games: Option<i32>,
+    #[serde(default)]
+    pub win_rate: Option<f64>,
+    #[serde(default)]
+    pub rarity: Option<Rarity>,
+}
+
+#[derive(Debug)]
+pub enum OverrideError {
+    Io(PathBuf, std::io::Error),
+    Parse(PathBuf, serde_json::Error),
+    MissingName(i32),
+}
+
+impl fmt::Display for OverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverrideError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            OverrideError::Parse(path, e) => write!(f, "{}: {}", path.display(), e),
+            OverrideError::MissingName(id) => write!(f, "new augment {} needs a name", id),
+        }
+    }
+}
+
+impl std::error::Error for OverrideError {}
+
+pub fn load_overrides(path: &Path) -> Result<DataOverrides, OverrideError> {
+    let text = std::fs::read_to_string(path).map_err(|e| OverrideError::Io(path.to_path_buf(), e))?;
+    serde_json::from_str(&text).map_err(|e| OverrideError::Parse(path.to_path_buf(), e))
+}
+
+pub fn apply_overrides(base: &MayhemData, overrides: &DataOverrides) -> Result<MayhemData, OverrideError> {
+    let mut data = base.clone();
+
+    if overrides.metadata.is_some() {
+        data.metadata = overrides.metadata.clone();
+    }
+
+    for patch in &overrides.augments {
+        match data.augments.iter_mut().find(|a| a.id == patch.id) {
+            Some(existing) => {
+                let augment = Arc::make_mut(existing);
+                if let Some(name) = &patch.name {
+                    for (lang, text) in &name.0 {
+                        augment.name.0.insert(*lang, text.clone());
+                    }
+                }
+                if patch.tier.is_some() {
+                    augment.tier = patch.tier.clone();
+                }
+                if patch.popularity.is_some() {
+                    augment.popularity = patch.popularity.clone();
+                }
+                if patch.games.is_some() {
+                    augment.games = patch.games;
+                }
+                if patch.win_rate.is_some() {
+                    augment.win_rate = patch.win_rate;
+                }
+                if patch.rarity.is_some() {
+                    augment.rarity = patch.rarity;
+                }
+            }
+            None => {
+                let name = patch.name.clone().ok_or(OverrideError::MissingName(patch.id))?;
+                data.augments.push(Arc::new(Augment {
+                    id: patch.id,
+                    name,
+                    tier: patch.tier.clone(),
+                    popularity: patch.popularity.clone(),
+                    games: patch.games,
+                    win_rate: patch.win_rate,
+                    rarity: patch.rarity,
+                }));
+            }
+        }
+    }
+
+    for (champion, entries) in &overrides.champions {
+        let stats = data.champions.entry(champion.to_lowercase()).or_default();
+        for entry in entries {
+            match stats.iter_mut().find(|c| c.id == entry.id) {
+                Some(existing) => *existing = entry.clone(),
+                None => stats.push(entry.clone()),
+            }
+        }
+    }
+
+    for synergy in &overrides.synergies {
+        let [a, b] = synergy.augments;
+        match data.synergies.iter_mut().find(|s| s.pairs(a, b)) {
+            Some(existing) => *existing = synergy.clone(),
+            None => data.synergies.push(synergy.clone()),
+        }
+    }
+
+    Ok(data)
+}
+
+pub struct DataStore {
+    base: Arc<MayhemData>,
+    // Bumped on every swap, so caches can tell reloaded data apart.
+    current: RwLock<(u64, Arc<MayhemData>)>,
+    override_path: Option<PathBuf>,
+    last_error: Mutex<Option<String>>,
+}
+
+impl DataStore {
+    pub fn new(base: MayhemData, override_path: Option<PathBuf>) -> Self {
+        let base = Arc::new(base);
+        let store = Self {
+            current: RwLock::new((0, Arc::clone(&base))),
+            base,
+            override_path,
+            last_error: Mutex::new(None),
+        };
+        store.reload();
+        store
+    }
+
+    pub fn get(&self) -> Arc<MayhemData> {
+        Arc::clone(&self.current.read().unwrap().1)
+    }
+
+    /// The current data with its generation, which every successful `reload` increments.
+    pub fn snapshot(&self) -> (u64, Arc<MayhemData>) {
+        let current = self.current.read().unwrap();
+        (current.0, Arc::clone(&current.1))
+    }
+
+    pub fn override_path(&self) -> Option<&Path> {
+        self.override_path.as_deref()
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().unwrap().clone()
+    }
+
+    // Keeps serving the previous data when the override file is broken.
+    pub fn reload(&self) -> bool {
+        let Some(path) = &self.override_path else {
+            return true;
+        };
+
+        let result = if path.exists() {
+            load_overrides(path)
+                .and_then(|overrides| apply_overrides(&self.base, &overrides))
+                .map(Arc::new)
+        } else {
+            Ok(Arc::clone(&self.base))
+        };
+
+        match result {
+            Ok(data) => {
+                let mut current = self.current.write().unwrap();
+                *current = (current.0 + 1, data);
+                drop(current);
+                *self.last_error.lock().unwrap() = None;
+                true
+            }
+            Err(e) => {
+                log(&format!("Failed to load data overrides: {}", e));
+                *self.last_error.lock().unwrap() = Some(e.to_string());
+                false
+            }
+        }
+    }
+
+    pub fn watch(self: &Arc<Self>) {
+        let Some(path) = self.override_path.clone() else {
+            return;
+        };
+
+        let store = Arc::clone(self);
+        std::thread::spawn(move || {
+            let mut last_modified = modified_time(&path);
+            loop {
+                std::thread::sleep(WATCH_INTERVAL);
+                let modified = modified_time(&path);
+                if modified != last_modified {
+                    last_modified = modified;
+                    store.reload();
+                }
+            }
+        });
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::config::config_file;
+use crate::data::{AttackRange, ChampionClass, ChampionInfo, DamageType, Name};
+use crate::live_client::{AllGameData, LiveClient};
+
+pub const RULES_FILE: &str = "team_rules.json";
+const DEFAULT_RULES: &str = include_str!("../data/team_rules.json");
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TeamComp {
+    // Champion keys, without the player's own champion.
+    pub allies: Vec<String>,
+    pub enemies: Vec<String>,
+}
+
+impl TeamComp {
+    pub fn from_game(game_data: &AllGameData) -> Option<Self> {
+        let me = LiveClient::get_my_player(game_data)?;
+        let mut comp = Self::default();
+
+        for player in &game_data.all_players {
+            if std::ptr::eq(player, me) {
+                continue;
+            }
+            let champion = LiveClient::champion_id(player);
+            if player.team == me.team {
+                comp.allies.push(champion);
+            } else {
+                comp.enemies.push(champion);
+            }
+        }
+
+        Some(comp)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Ally,
+    #[default]
+    Enemy,
+}
+
+// A champion counts when it matches every filter that is set; list filters match any entry.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Condition {
+    pub side: Side,
+    pub classes: Vec<ChampionClass>,
+    pub damage_type: Option<DamageType>,
+    pub range: Option<AttackRange>,
+    pub champions: Vec<String>,
+    pub at_least: usize,
+}
+
+impl Condition {
+    pub fn count(&self, comp: &TeamComp, champions: &HashMap<String, ChampionInfo>) -> usize {
+        let side = match self.side {
+            Side::Ally => &comp.allies,
+            Side::Enemy => &comp.enemies,
+        };
+
+        side.iter()
+            .filter(|key| {
+                if !self.champions.is_empty() && !self.champions.iter().any(|c| c.eq_ignore_ascii_case(key)) {
+                    return false;
+                }
+
+                let info = champions.get(key.as_str());
+                let class_ok = self.classes.is_empty()
+                    || info.is_some_and(|i| self.classes.iter().any(|c| i.has_class(*c)));
+                let damage_ok = self.damage_type.is_none() || info.and_then(|i| i.damage_type) == self.damage_type;
+                let range_ok = self.range.is_none() || info.and_then(|i| i.range) == self.range;
+
+                class_ok && damage_ok && range_ok
+            })
+            .count()
+    }
+
+    pub fn matches(&self, comp: &TeamComp, champions: &HashMap<String, ChampionInfo>) -> bool {
+        self.count(comp, champions) >= self.at_least.max(1)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CompRule {
+    pub name: String,
+    pub when: Condition,
+    pub augments: Vec<i32>,
+    // Added to the card score, before the `team_comp` weight.
+    pub adjust: f64,
+    #[serde(default)]
+    pub reason: Name,
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Io(PathBuf, std::io::Error),
+    Parse(String, serde_json::Error),
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            RulesError::Parse(source, e) => write!(f, "{}: {}", source, e),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+pub fn parse_rules(source: &str, text: &str) -> Result<Vec<CompRule>, RulesError> {
+    serde_json::from_str(text).map_err(|e| RulesError::Parse(source.to_string(), e))
+}
+
+pub fn default_rules() -> Vec<CompRule> {
+    parse_rules(RULES_FILE, DEFAULT_RULES).expect("Failed to parse built-in team rules")
+}
+
+/// `team_rules.json` from the config directory, or the built-in rules when it is missing or broken.
+pub fn load_rules() -> Vec<CompRule> {
+    let Some(path) = config_file(RULES_FILE).filter(|p| p.exists()) else {
+        return default_rules();
+    };
+
+    std::fs::read_to_string(&path)
+        .map_err(|e| RulesError::Io(path.clone(), e))
+        .and_then(|text| parse_rules(&path.display().to_string(), &text))
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to load team rules: {}", e);
+            default_rules()
+        })
+}
+
+pub fn active_rules(rules: &[CompRule], comp: &TeamComp, champions: &HashMap<String, ChampionInfo>) -> Vec<CompRule> {
+    rules.iter().filter(|r| r.when.matches(comp, champions)).cloned().collect()
+}
@@ -0,0 +1,70 @@
+use crate::live_client::{AllGameData, EventKind};
+
+// Champion levels at which Mayhem offers an augment.
+pub const AUGMENT_LEVELS: [i32; 4] = [3, 7, 11, 15];
+// Game seconds a window stays open when no pick is seen, e.g. when the panel was never read.
+const WINDOW_TIMEOUT: f64 = 120.0;
+
+/// Predicts when an augment panel can be on screen, so OCR only runs around level-ups.
+#[derive(Clone, Debug)]
+pub struct AugmentTiming {
+    levels: Vec<i32>,
+    // Game time each level was first seen at.
+    reached: Vec<Option<f64>>,
+    game_time: f64,
+    // Without a level (spectating, old clients) every moment counts as a window.
+    level_known: bool,
+    ended: bool,
+}
+
+impl AugmentTiming {
+    pub fn new(levels: &[i32]) -> Self {
+        Self {
+            levels: levels.to_vec(),
+            reached: vec![None; levels.len()],
+            game_time: 0.0,
+            level_known: false,
+            ended: false,
+        }
+    }
+
+    pub fn update(&mut self, game_data: &AllGameData) {
+        self.game_time = game_data.game_data.game_time;
+        self.ended = game_data.events.events.iter().any(|e| matches!(e.kind(), EventKind::GameEnd { .. }));
+
+        let level = game_data.active_player.level;
+        self.level_known = level > 0;
+
+        for (threshold, reached) in self.levels.iter().zip(&mut self.reached) {
+            if level >= *threshold && reached.is_none() {
+                *reached = Some(self.game_time);
+            }
+        }
+    }
+
+    /// `picks` is how many augments the session has recorded. The window stays open while
+    /// fewer picks than reached levels are recorded, up to `WINDOW_TIMEOUT` after the last level-up.
+    pub fn is_open(&self, picks: usize) -> bool {
+        if self.ended {
+            return false;
+        }
+        if !self.level_known {
+            return true;
+        }
+
+        let last = self.reached.iter().flatten().fold(f64::MIN, |a, b| a.max(*b));
+        self.is_pending(picks) && self.game_time - last < WINDOW_TIMEOUT
+    }
+
+    /// Whether a reached level still has no recorded pick, even after its window timed out;
+    /// the player may simply take longer to choose.
+    pub fn is_pending(&self, picks: usize) -> bool {
+        !self.ended && self.reached.iter().flatten().count() > picks
+    }
+}
+
+impl Default for AugmentTiming {
+    fn default() -> Self {
+        Self::new(&AUGMENT_LEVELS)
+    }
+}
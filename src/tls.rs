@@ -0,0 +1,143 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{VerifierBuilderError, WebPkiServerVerifier};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+
+// Riot's root for the game client's certificate, from
+// https://static.developer.riotgames.com/docs/lol/riotgames.pem (empty when the build didn't have it).
+const RIOT_ROOT_CA: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/riotgames.pem"));
+
+#[derive(Clone, Debug, Default)]
+pub enum TlsMode {
+    #[default]
+    RiotRoot,
+    // PEM roots to trust instead of Riot's, e.g. the mock server's test CA.
+    Pinned(Vec<u8>),
+    // Accepts any certificate; only with `insecure_live_client` in the config.
+    Insecure,
+}
+
+#[derive(Debug)]
+pub enum TlsError {
+    Io(PathBuf, std::io::Error),
+    Pem(std::io::Error),
+    // The build had no `data/riotgames.pem` to embed.
+    MissingRiotRoot,
+    NoCertificate,
+    Certificate(rustls::Error),
+    Verifier(VerifierBuilderError),
+    Client(reqwest::Error),
+}
+
+impl fmt::Display for TlsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TlsError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            TlsError::Pem(e) => write!(f, "invalid PEM: {}", e),
+            TlsError::MissingRiotRoot => write!(f, "this build has no Riot root certificate; add data/riotgames.pem and rebuild"),
+            TlsError::NoCertificate => write!(f, "no root certificate to pin"),
+            TlsError::Certificate(e) => write!(f, "invalid root certificate: {}", e),
+            TlsError::Verifier(e) => write!(f, "{}", e),
+            TlsError::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TlsError {}
+
+impl TlsMode {
+    pub fn pinned_file(path: PathBuf) -> Result<Self, TlsError> {
+        std::fs::read(&path).map(TlsMode::Pinned).map_err(|e| TlsError::Io(path, e))
+    }
+
+    /// `None` for `Insecure`, which the HTTP client handles on its own.
+    pub fn client_config(&self) -> Result<Option<ClientConfig>, TlsError> {
+        let pem = match self {
+            TlsMode::RiotRoot if RIOT_ROOT_CA.is_empty() => return Err(TlsError::MissingRiotRoot),
+            TlsMode::RiotRoot => RIOT_ROOT_CA,
+            TlsMode::Pinned(pem) => pem.as_slice(),
+            TlsMode::Insecure => return Ok(None),
+        };
+
+        let mut roots = RootCertStore::empty();
+        for cert in rustls_pemfile::certs(&mut &pem[..]) {
+            roots.add(cert.map_err(TlsError::Pem)?).map_err(TlsError::Certificate)?;
+        }
+        if roots.is_empty() {
+            return Err(TlsError::NoCertificate);
+        }
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let inner = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), Arc::clone(&provider))
+            .build()
+            .map_err(TlsError::Verifier)?;
+
+        let config = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(TlsError::Certificate)?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(LoopbackVerifier { inner }))
+            .with_no_client_auth();
+
+        Ok(Some(config))
+    }
+}
+
+// The game's certificate is issued by the pinned root but doesn't name 127.0.0.1 in a way webpki accepts,
+// so a name mismatch is forgiven for loopback addresses only; the chain is still verified.
+#[derive(Debug)]
+struct LoopbackVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+}
+
+fn is_loopback(name: &ServerName) -> bool {
+    match name {
+        ServerName::IpAddress(ip) => std::net::IpAddr::from(*ip).is_loopback(),
+        ServerName::DnsName(dns) => dns.as_ref().eq_ignore_ascii_case("localhost"),
+        _ => false,
+    }
+}
+
+impl ServerCertVerifier for LoopbackVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        match self.inner.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now) {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) if is_loopback(server_name) => Ok(ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
@@ -13,6 +13,7 @@ use windows::Win32::UI::WindowsAndMessaging::{
 const ICON: &[u8] = include_bytes!("../data/icon.ico");
 
 pub enum TrayCommand {
+    OpenLog,
     Exit,
 }
 
@@ -32,6 +33,9 @@ impl Tray {
 
         std::thread::spawn(move || {
             let menu = Menu::new();
+            let log_item = MenuItem::new("로그 열기", true, None);
+            let log_id = log_item.id().clone();
+            menu.append(&log_item).ok();
             let exit_item = MenuItem::new("종료", true, None);
             let exit_id = exit_item.id().clone();
             menu.append(&exit_item).ok();
@@ -64,6 +68,9 @@ impl Tray {
                 }
 
                 if let Ok(event) = MenuEvent::receiver().try_recv() {
+                    if event.id == log_id {
+                        let _ = cmd_tx.send(TrayCommand::OpenLog);
+                    }
                     if event.id == exit_id {
                         let _ = cmd_tx.send(TrayCommand::Exit);
                         break;
@@ -0,0 +1,233 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use crate::data::{parse_percent, Augment, ChampionInfo, Language, MayhemData, Name, NO_CHAMPION, TIERS};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IssueKind {
+    DuplicateAugmentId { id: i32 },
+    DanglingAugmentId { champion: String, id: i32 },
+    DuplicateChampionAugment { champion: String, id: i32 },
+    DanglingSynergyAugment { pair: [i32; 2], id: i32 },
+    DuplicateSynergy { pair: [i32; 2] },
+    UnknownChampion { champion: String },
+    ChampionWithoutStats { champion: String },
+    DuplicateChampionKey { key: i32, champions: Vec<String> },
+    MissingTranslation { owner: String, language: Language },
+    DuplicateName { language: Language, name: String, ids: Vec<String> },
+    InvalidTier { owner: String, tier: String },
+    InvalidPopularity { owner: String, popularity: String },
+    InvalidGames { owner: String, games: i32 },
+    InvalidWinRate { owner: String, win_rate: f64 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}: ", label)?;
+
+        match &self.kind {
+            IssueKind::DuplicateAugmentId { id } => write!(f, "augment id {} is defined more than once", id),
+            IssueKind::DanglingAugmentId { champion, id } => write!(f, "{} references unknown augment id {}", champion, id),
+            IssueKind::DuplicateChampionAugment { champion, id } => write!(f, "{} lists augment {} more than once", champion, id),
+            IssueKind::DanglingSynergyAugment { pair, id } => write!(f, "synergy {}+{} references unknown augment id {}", pair[0], pair[1], id),
+            IssueKind::DuplicateSynergy { pair } => write!(f, "synergy {}+{} is defined more than once", pair[0], pair[1]),
+            IssueKind::UnknownChampion { champion } => write!(f, "{} has stats but no entry in champions.json", champion),
+            IssueKind::ChampionWithoutStats { champion } => write!(f, "{} is in champions.json but has no stats", champion),
+            IssueKind::DuplicateChampionKey { key, champions } => write!(f, "champion key {} is shared by {}", key, champions.join(", ")),
+            IssueKind::MissingTranslation { owner, language } => write!(f, "{} has no {} name", owner, language),
+            IssueKind::DuplicateName { language, name, ids } => write!(f, "{} name \"{}\" is shared by {}", language, name, ids.join(", ")),
+            IssueKind::InvalidTier { owner, tier } => write!(f, "{} has unknown tier \"{}\"", owner, tier),
+            IssueKind::InvalidPopularity { owner, popularity } => write!(f, "{} has out-of-range popularity \"{}\"", owner, popularity),
+            IssueKind::InvalidGames { owner, games } => write!(f, "{} has out-of-range games {}", owner, games),
+            IssueKind::InvalidWinRate { owner, win_rate } => write!(f, "{} has out-of-range win rate {}", owner, win_rate),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    fn push(&mut self, severity: Severity, kind: IssueKind) {
+        self.issues.push(Issue { severity, kind });
+    }
+
+    pub fn errors(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Error).count()
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Warning).count()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.errors() == 0
+    }
+}
+
+pub fn validate(mayhem: &MayhemData, champions: &HashMap<String, ChampionInfo>) -> Report {
+    let mut report = Report::default();
+
+    let mut augment_ids = HashSet::new();
+    for augment in &mayhem.augments {
+        if !augment_ids.insert(augment.id) {
+            report.push(Severity::Error, IssueKind::DuplicateAugmentId { id: augment.id });
+        }
+        validate_augment(&mut report, augment);
+    }
+
+    check_duplicate_names(
+        &mut report,
+        mayhem.augments.iter().map(|a| (format!("augment {}", a.id), &a.name)),
+    );
+
+    let mut champion_keys: Vec<_> = mayhem.champions.keys().collect();
+    champion_keys.sort();
+
+    for key in champion_keys {
+        if !champions.contains_key(key) {
+            report.push(Severity::Error, IssueKind::UnknownChampion { champion: key.clone() });
+        }
+
+        let mut seen = HashSet::new();
+        for entry in &mayhem.champions[key] {
+            if !augment_ids.contains(&entry.id) {
+                report.push(Severity::Error, IssueKind::DanglingAugmentId { champion: key.clone(), id: entry.id });
+            }
+            if !seen.insert(entry.id) {
+                report.push(Severity::Error, IssueKind::DuplicateChampionAugment { champion: key.clone(), id: entry.id });
+            }
+
+            let owner = format!("{}/{}", key, entry.id);
+            validate_stats(&mut report, &owner, Some(&entry.tier), Some(&entry.popularity), Some(entry.games));
+            validate_win_rate(&mut report, &owner, entry.win_rate);
+        }
+    }
+
+    let mut pairs = HashSet::new();
+    for synergy in &mayhem.synergies {
+        let pair = synergy.augments;
+        for id in pair {
+            if !augment_ids.contains(&id) {
+                report.push(Severity::Error, IssueKind::DanglingSynergyAugment { pair, id });
+            }
+        }
+        if !pairs.insert((pair[0].min(pair[1]), pair[0].max(pair[1]))) {
+            report.push(Severity::Error, IssueKind::DuplicateSynergy { pair });
+        }
+
+        let owner = format!("synergy {}+{}", pair[0], pair[1]);
+        validate_stats(&mut report, &owner, Some(&synergy.tier), None, Some(synergy.games));
+        validate_win_rate(&mut report, &owner, synergy.win_rate);
+    }
+
+    let mut info_keys: Vec<_> = champions.keys().collect();
+    info_keys.sort();
+
+    let mut by_riot_key: BTreeMap<i32, Vec<String>> = BTreeMap::new();
+    for key in &info_keys {
+        if *key != NO_CHAMPION && !mayhem.champions.contains_key(*key) {
+            report.push(Severity::Warning, IssueKind::ChampionWithoutStats { champion: (*key).clone() });
+        }
+        check_translations(&mut report, &format!("champion {}", key), &champions[*key].name);
+
+        if let Some(riot_key) = champions[*key].key {
+            by_riot_key.entry(riot_key).or_default().push((*key).clone());
+        }
+    }
+
+    for (key, champions) in by_riot_key {
+        if champions.len() > 1 {
+            report.push(Severity::Error, IssueKind::DuplicateChampionKey { key, champions });
+        }
+    }
+
+    check_duplicate_names(
+        &mut report,
+        info_keys.iter().map(|key| (format!("champion {}", key), &champions[*key].name)),
+    );
+
+    report
+}
+
+fn validate_augment(report: &mut Report, augment: &Augment) {
+    let owner = format!("augment {}", augment.id);
+    check_translations(report, &owner, &augment.name);
+    validate_stats(report, &owner, augment.tier.as_ref(), augment.popularity.as_ref(), augment.games);
+    validate_win_rate(report, &owner, augment.win_rate);
+}
+
+fn check_translations(report: &mut Report, owner: &str, name: &Name) {
+    for language in Language::ALL {
+        if name.get_exact(language).is_none() {
+            report.push(Severity::Error, IssueKind::MissingTranslation { owner: owner.to_string(), language });
+        }
+    }
+}
+
+fn check_duplicate_names<'a>(report: &mut Report, names: impl Iterator<Item = (String, &'a Name)>) {
+    let names: Vec<_> = names.collect();
+
+    for language in Language::ALL {
+        let mut by_name: HashMap<String, Vec<String>> = HashMap::new();
+        for (owner, name) in &names {
+            if let Some(text) = name.get_exact(language) {
+                by_name.entry(text.trim().to_lowercase()).or_default().push(owner.clone());
+            }
+        }
+
+        let mut duplicates: Vec<_> = by_name.into_iter().filter(|(_, ids)| ids.len() > 1).collect();
+        duplicates.sort();
+
+        for (name, ids) in duplicates {
+            report.push(Severity::Warning, IssueKind::DuplicateName { language, name, ids });
+        }
+    }
+}
+
+fn validate_stats(report: &mut Report, owner: &str, tier: Option<&String>, popularity: Option<&String>, games: Option<i32>) {
+    if let Some(tier) = tier
+        && !TIERS.contains(&tier.as_str())
+    {
+        report.push(Severity::Error, IssueKind::InvalidTier { owner: owner.to_string(), tier: tier.clone() });
+    }
+
+    if let Some(popularity) = popularity {
+        let valid = parse_percent(popularity).is_some_and(|p| (0.0..=100.0).contains(&p));
+        if !valid {
+            report.push(Severity::Error, IssueKind::InvalidPopularity { owner: owner.to_string(), popularity: popularity.clone() });
+        }
+    }
+
+    if let Some(games) = games
+        && games < 0
+    {
+        report.push(Severity::Error, IssueKind::InvalidGames { owner: owner.to_string(), games });
+    }
+}
+
+fn validate_win_rate(report: &mut Report, owner: &str, win_rate: Option<f64>) {
+    if let Some(win_rate) = win_rate
+        && !(0.0..=1.0).contains(&win_rate)
+    {
+        report.push(Severity::Error, IssueKind::InvalidWinRate { owner: owner.to_string(), win_rate });
+    }
+}
@@ -0,0 +1,68 @@
+use aram_mayhem_advisor::data::{Language, CHAMPIONS_DATA};
+use aram_mayhem_advisor::resolver::CHAMPION_RESOLVER;
+
+#[test]
+fn resolves_every_data_key() {
+    for id in CHAMPIONS_DATA.keys() {
+        assert_eq!(CHAMPION_RESOLVER.resolve(id), Some(id.as_str()), "key {}", id);
+        assert_eq!(CHAMPION_RESOLVER.resolve(&id.to_uppercase()), Some(id.as_str()), "key {}", id);
+    }
+}
+
+#[test]
+fn resolves_every_raw_champion_name() {
+    for id in CHAMPIONS_DATA.keys() {
+        let raw = format!("game_character_displayname_{}", id);
+        assert_eq!(CHAMPION_RESOLVER.resolve(&raw), Some(id.as_str()), "raw name {}", raw);
+    }
+}
+
+#[test]
+fn resolves_every_numeric_key() {
+    for (id, info) in CHAMPIONS_DATA.iter() {
+        if let Some(key) = info.key {
+            assert_eq!(CHAMPION_RESOLVER.resolve(&key.to_string()), Some(id.as_str()), "numeric key {}", key);
+        }
+    }
+}
+
+#[test]
+fn resolves_every_localized_name() {
+    for (id, info) in CHAMPIONS_DATA.iter() {
+        for language in Language::ALL {
+            let name = info.name.get(language);
+            assert_eq!(CHAMPION_RESOLVER.resolve(name), Some(id.as_str()), "{} name {:?}", language, name);
+        }
+    }
+}
+
+#[test]
+fn resolves_internal_names_that_differ_from_display_names() {
+    let cases = [
+        ("game_character_displayname_MonkeyKing", "monkeyking"),
+        ("Wukong", "monkeyking"),
+        ("오공", "monkeyking"),
+        ("game_character_displayname_Nunu", "nunu"),
+        ("Nunu & Willump", "nunu"),
+        ("game_character_displayname_Renata", "renata"),
+        ("Renata Glasc", "renata"),
+        ("Kai'Sa", "kaisa"),
+        ("Dr. Mundo", "drmundo"),
+        ("Bel'Veth", "belveth"),
+        ("K'Sante", "ksante"),
+        ("FiddleSticks", "fiddlesticks"),
+        ("62", "monkeyking"),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(CHAMPION_RESOLVER.resolve(input), Some(expected), "{}", input);
+    }
+}
+
+#[test]
+fn unknown_names_do_not_resolve() {
+    assert_eq!(CHAMPION_RESOLVER.resolve(""), None);
+    assert_eq!(CHAMPION_RESOLVER.resolve("game_character_displayname_"), None);
+    assert_eq!(CHAMPION_RESOLVER.resolve("NotAChampion"), None);
+    assert_eq!(CHAMPION_RESOLVER.resolve("0"), None);
+}
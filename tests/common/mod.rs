@@ -0,0 +1,83 @@
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use aram_mayhem_advisor::data::{Augment, Name};
+use aram_mayhem_advisor::tls::TlsMode;
+
+// Generous so a loaded CI machine doesn't fail a test that is only slow.
+const DEADLINE: Duration = Duration::from_secs(30);
+
+/// A nameless augment with only a tier, for scoring tests.
+pub fn augment(id: i32, tier: &str) -> Augment {
+    Augment {
+        id,
+        name: Name::default(),
+        tier: Some(tier.to_string()),
+        popularity: None,
+        games: None,
+        win_rate: None,
+        rarity: None,
+    }
+}
+
+pub struct MockServer {
+    child: Child,
+    pub url: String,
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.child.kill().ok();
+    }
+}
+
+pub fn fixtures() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))
+}
+
+pub fn pinned(file: &str) -> TlsMode {
+    TlsMode::pinned_file(fixtures().join("tls").join(file)).unwrap()
+}
+
+/// Writes a timeline with `steps` to the temp dir, unique per test `name`.
+pub fn write_timeline(name: &str, steps: serde_json::Value) -> PathBuf {
+    let timeline = serde_json::json!({ "version": "15.22.1.1", "steps": steps });
+    let path = std::env::temp_dir().join(format!("aram-mayhem-timeline-{}-{}.json", name, std::process::id()));
+    std::fs::write(&path, timeline.to_string()).unwrap();
+    path
+}
+
+pub fn start_mock(timeline: &Path, speed: f64) -> MockServer {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_mock-live-client"))
+        .arg(timeline)
+        .args(["--port", "0", "--speed", &speed.to_string()])
+        .arg("--cert")
+        .arg(fixtures().join("tls/mock.crt"))
+        .arg("--key")
+        .arg(fixtures().join("tls/mock.key"))
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+    let url = line.trim().strip_prefix("listening on ").unwrap_or_else(|| panic!("unexpected output: {}", line)).to_string();
+
+    MockServer { child, url }
+}
+
+/// Retries `check` until it returns a value, failing the test after `DEADLINE`.
+pub async fn eventually<T>(what: &str, mut check: impl AsyncFnMut() -> Option<T>) -> T {
+    let deadline = Instant::now() + DEADLINE;
+    loop {
+        if let Some(value) = check().await {
+            return value;
+        }
+        assert!(Instant::now() < deadline, "timed out waiting for {}", what);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+}
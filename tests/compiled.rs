@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use aram_mayhem_advisor::compiled::{
+    decode_champions, decode_mayhem, CHAMPIONS_BIN, CLASS_KEYS, DAMAGE_KEYS, LANGUAGE_KEYS, MAYHEM_BIN, RANGE_KEYS,
+    RARITY_KEYS,
+};
+use aram_mayhem_advisor::data::{AttackRange, ChampionClass, ChampionInfo, DamageType, Language, MayhemData, Rarity};
+use serde::Serialize;
+
+fn source<T: serde::de::DeserializeOwned>(file: &str) -> T {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/").to_string() + file;
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn keys<T: Serialize>(values: &[T]) -> Vec<String> {
+    values.iter().map(|v| serde_json::to_value(v).unwrap().as_str().unwrap().to_string()).collect()
+}
+
+#[test]
+fn mayhem_data_survives_the_build() {
+    let mut parsed: MayhemData = source("mayhem.json");
+    parsed.augments.sort_by_key(|a| a.id);
+    let decoded = decode_mayhem(MAYHEM_BIN).unwrap();
+
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&parsed).unwrap());
+}
+
+#[test]
+fn champion_data_survives_the_build() {
+    let parsed: HashMap<String, ChampionInfo> = source("champions.json");
+    let decoded = decode_champions(CHAMPIONS_BIN).unwrap();
+
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&parsed).unwrap());
+}
+
+#[test]
+fn layout_follows_the_enums() {
+    assert_eq!(LANGUAGE_KEYS.to_vec(), Language::ALL.map(Language::key).to_vec());
+    assert_eq!(keys(&ChampionClass::ALL), CLASS_KEYS);
+    assert_eq!(keys(&DamageType::ALL), DAMAGE_KEYS);
+    assert_eq!(keys(&AttackRange::ALL), RANGE_KEYS);
+    assert_eq!(keys(&Rarity::ALL), RARITY_KEYS);
+}
+
+#[test]
+fn rejects_foreign_bytes() {
+    assert!(decode_mayhem(b"nope").is_err());
+    assert!(decode_mayhem(&MAYHEM_BIN[..MAYHEM_BIN.len() / 2]).is_err());
+}
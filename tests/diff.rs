@@ -0,0 +1,79 @@
+use aram_mayhem_advisor::data::{Language, MayhemData};
+use aram_mayhem_advisor::diff::{diff, render, DataDiff, DiffFormat, DiffOptions};
+
+fn data(text: &str) -> MayhemData {
+    serde_json::from_str(text).unwrap()
+}
+
+fn sample() -> DataDiff {
+    let old = data(
+        r#"{
+            "champions": {
+                "lux": [{ "id": 1, "tier": "A", "popularity": "10%", "games": 1000 }],
+                "zed": [{ "id": 1, "tier": "B", "popularity": "5%", "games": 500 }]
+            },
+            "augments": [
+                { "id": 1, "name": { "ko_kr": "하나", "en_gb": "One|Two" }, "tier": "B", "games": 500 },
+                { "id": 2, "name": { "ko_kr": "둘", "en_gb": "Gone" }, "tier": "C" }
+            ],
+            "synergies": []
+        }"#,
+    );
+    let new = data(
+        r#"{
+            "champions": {
+                "lux": [{ "id": 1, "tier": "S", "popularity": "12%", "games": 1000 }],
+                "ahri": [{ "id": 1, "tier": "A", "popularity": "3%", "games": 200 }]
+            },
+            "augments": [
+                { "id": 1, "name": { "ko_kr": "하나", "en_gb": "One|Two" }, "tier": "A", "games": 500 },
+                { "id": 3, "name": { "ko_kr": "셋", "en_gb": "New" }, "tier": "S" }
+            ],
+            "synergies": []
+        }"#,
+    );
+    let options = DiffOptions { language: Language::EnGb, ..DiffOptions::default() };
+    diff(&old, &new, &options)
+}
+
+#[test]
+fn renders_text() {
+    let text = render(&sample(), DiffFormat::Text);
+    assert!(text.contains("+ augment 3 New\n"));
+    assert!(text.contains("- augment 2 Gone\n"));
+    assert!(text.contains("+ champion ahri\n"));
+    assert!(text.contains("- champion zed\n"));
+    assert!(text.contains("~ One|Two (1): B -> A\n"));
+    assert!(text.contains("~ lux / One|Two (1): A -> S\n"));
+    assert!(text.contains("* lux / One|Two (1): popularity 10% -> 12%, games 1000 -> 1000\n"));
+
+    assert_eq!(render(&DataDiff::default(), DiffFormat::Text), "no changes\n");
+}
+
+#[test]
+fn renders_json() {
+    let json: serde_json::Value = serde_json::from_str(&render(&sample(), DiffFormat::Json)).unwrap();
+    assert_eq!(json["augments_added"][0]["id"], 3);
+    assert_eq!(json["champions_removed"][0], "zed");
+    assert_eq!(json["tier_changes"][0]["old"], "B");
+    assert_eq!(json["champion_tier_changes"][0]["champion"], "lux");
+    assert_eq!(json["swings"][0]["new_popularity"], "12%");
+}
+
+#[test]
+fn renders_markdown_with_escaped_cells() {
+    let markdown = render(&sample(), DiffFormat::Markdown);
+    assert!(markdown.starts_with("## Data changes\n\n"));
+    assert!(markdown.contains("- Added **New** (3)\n"));
+    assert!(markdown.contains("- Removed `zed`\n"));
+    assert!(markdown.contains("| - | One\\|Two (1) | B | A |\n"));
+    assert!(markdown.contains("| lux | One\\|Two (1) | A | S |\n"));
+    assert!(markdown.contains("| lux | One\\|Two (1) | 10% → 12% | 1000 → 1000 |\n"));
+
+    // Every table row keeps exactly its four columns.
+    for row in markdown.lines().filter(|line| line.starts_with('|')) {
+        assert_eq!(row.replace("\\|", "").matches('|').count(), 5, "{}", row);
+    }
+
+    assert!(render(&DataDiff::default(), DiffFormat::Markdown).ends_with("No changes.\n"));
+}
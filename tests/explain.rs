@@ -0,0 +1,96 @@
+use aram_mayhem_advisor::data::{ChampionClass, Language, MayhemData, Name, Rarity};
+use aram_mayhem_advisor::explain::{group_thousands, Explanation, EXPLANATION_LANGUAGES};
+
+fn data() -> MayhemData {
+    serde_json::from_str(
+        r#"{
+            "champions": {},
+            "augments": [{ "id": 7, "name": { "ko_kr": "연쇄 폭발", "en_gb": "Chain Blast" } }]
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn reads_in_korean_and_english() {
+    let data = data();
+    let tier = Explanation::ChampionTier { tier: "S".to_string(), games: 12_345 };
+
+    assert_eq!(tier.text(Language::KoKr, &data), "이 챔피언 기준 S 티어 (12,345게임)");
+    assert_eq!(tier.text(Language::EnGb, &data), "S tier on this champion over 12,345 games");
+
+    let class = Explanation::ClassTier { class: ChampionClass::Marksman, tier: "A".to_string(), games: 900 };
+    assert_eq!(class.text(Language::KoKr, &data), "원거리 딜러 평균 A 티어 (900게임)");
+    assert_eq!(class.text(Language::EnGb, &data), "A tier for marksmen over 900 games");
+
+    assert_eq!(Explanation::Rarity(Rarity::Prismatic).text(Language::KoKr, &data), "프리즘 증강");
+}
+
+#[test]
+fn every_table_has_its_own_text() {
+    let data = data();
+    let english = Explanation::NoChampionData.text(Language::EnGb, &data);
+    let tier = Explanation::ChampionTier { tier: "S".to_string(), games: 1_234 };
+
+    for lang in EXPLANATION_LANGUAGES.into_iter().filter(|l| *l != Language::EnGb) {
+        assert_ne!(Explanation::NoChampionData.text(lang, &data), english, "{}", lang);
+        let text = tier.text(lang, &data);
+        assert!(text.contains('S') && text.contains("1,234") && !text.contains('{'), "{}: {}", lang, text);
+    }
+
+    assert_eq!(Explanation::Rarity(Rarity::Gold).text(Language::DeDe, &data), "Gold-Augment");
+    assert_eq!(
+        Explanation::ClassTier { class: ChampionClass::Mage, tier: "B".to_string(), games: 50 }.text(Language::FrFr, &data),
+        "Tier B pour les mages sur 50 parties"
+    );
+}
+
+#[test]
+fn other_languages_read_their_fallback_table() {
+    let data = data();
+
+    for lang in Language::ALL.into_iter().filter(|l| !EXPLANATION_LANGUAGES.contains(l)) {
+        let table = lang.fallback_chain().find(|l| EXPLANATION_LANGUAGES.contains(l)).unwrap();
+        assert_eq!(Explanation::NoChampionData.text(lang, &data), Explanation::NoChampionData.text(table, &data), "{}", lang);
+    }
+    assert_eq!(
+        Explanation::NoChampionData.text(Language::EsAr, &data),
+        Explanation::NoChampionData.text(Language::EsEs, &data)
+    );
+    assert_eq!(
+        Explanation::NoChampionData.text(Language::EnAu, &data),
+        Explanation::NoChampionData.text(Language::EnGb, &data)
+    );
+}
+
+#[test]
+fn names_synergy_partners_in_the_language() {
+    let data = data();
+    let known = Explanation::Synergy { partner: 7, tier: "S".to_string() };
+    let unknown = Explanation::Synergy { partner: 99, tier: "A".to_string() };
+
+    assert_eq!(known.text(Language::KoKr, &data), "이전에 고른 연쇄 폭발와(과) 시너지 S");
+    assert_eq!(known.text(Language::EnAu, &data), "Synergizes with your earlier pick Chain Blast (S)");
+    assert_eq!(unknown.text(Language::EnGb, &data), "Synergizes with your earlier pick 99 (A)");
+}
+
+#[test]
+fn rules_read_their_reason_or_name() {
+    let data = data();
+    let reason: Name = serde_json::from_str(r#"{ "ko_kr": "탱커가 많음" }"#).unwrap();
+    let rule = Explanation::Rule { rule: "tanks".to_string(), reason };
+
+    assert_eq!(rule.text(Language::KoKr, &data), "탱커가 많음");
+    assert_eq!(rule.text(Language::EnGb, &data), "tanks");
+}
+
+#[test]
+fn groups_thousands() {
+    assert_eq!(group_thousands(0), "0");
+    assert_eq!(group_thousands(999), "999");
+    assert_eq!(group_thousands(1000), "1,000");
+    assert_eq!(group_thousands(123_456), "123,456");
+    assert_eq!(group_thousands(1_234_567), "1,234,567");
+    assert_eq!(group_thousands(-4321), "-4,321");
+    assert_eq!(group_thousands(i32::MIN), "-2,147,483,648");
+}
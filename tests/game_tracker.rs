@@ -0,0 +1,172 @@
+mod common;
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use aram_mayhem_advisor::game::{GameTracker, PollEvent, CAPTURE_INTERVAL, END_AFTER_NOT_FOUND, FALLBACK_CAPTURE_INTERVAL};
+use aram_mayhem_advisor::live_client::{AllGameData, GameDataSource, LiveClient, LiveClientError};
+use aram_mayhem_advisor::timing::AUGMENT_LEVELS;
+
+use common::{eventually, fixtures, pinned, start_mock};
+
+// Frames without cards before the session counts the panel as closed.
+const CLOSE_FRAMES: usize = 10;
+
+#[tokio::test]
+async fn follows_the_timeline_from_loading_to_the_end() {
+    // timeline.json at 20x: 0.5s loading, 6s in game, 0.25s after GameEnd.
+    let server = start_mock(&fixtures().join("live_client/timeline.json"), 20.0);
+    let client = LiveClient::with_tls(&server.url, &pinned("mock-ca.crt")).unwrap();
+    let mut tracker = GameTracker::new(&AUGMENT_LEVELS, Vec::new());
+
+    eventually("the game to start", async || {
+        let result = tracker.poll(&client).await;
+        match result.event {
+            PollEvent::Started => Some(()),
+            PollEvent::Idle => {
+                assert!(matches!(result.error, Some(LiveClientError::Status(404))), "{:?}", result.error);
+                assert!(tracker.game().is_none());
+                None
+            }
+            event => panic!("unexpected {:?} while loading", event),
+        }
+    })
+    .await;
+
+    let game = tracker.game_mut().unwrap();
+    assert!(game.mayhem);
+    assert_eq!(game.version.as_deref(), Some("15.22.719.4137"));
+    assert_eq!(game.champion.as_deref(), Some("vayne"));
+    assert_eq!(game.comp.as_ref().unwrap().enemies.len(), 5);
+    // Level 9 reached two augment levels and nothing was picked yet.
+    assert!(game.is_window_open());
+
+    for _ in 0..3 {
+        game.session.observe(Some([Some(1), Some(2), Some(3)]));
+    }
+    for _ in 0..CLOSE_FRAMES {
+        game.session.observe(None);
+    }
+    assert_eq!(game.session.picks().len(), 1);
+
+    eventually("the game to end", async || {
+        let result = tracker.poll(&client).await;
+        match result.event {
+            PollEvent::InGame => {
+                assert_eq!(tracker.game().unwrap().session.picks().len(), 1);
+                None
+            }
+            // A slow or cut-off request is skipped, not the end of the game.
+            PollEvent::Idle => {
+                assert!(result.error.is_some());
+                assert!(tracker.game().is_some());
+                None
+            }
+            PollEvent::Ended => Some(()),
+            event => panic!("unexpected {:?} in game", event),
+        }
+    })
+    .await;
+
+    assert!(tracker.game().is_none());
+    assert_eq!(tracker.poll(&client).await.event, PollEvent::Idle);
+}
+
+// Answers each poll from a script instead of a server.
+struct Scripted(Mutex<VecDeque<Result<AllGameData, LiveClientError>>>);
+
+impl Scripted {
+    fn new(polls: impl IntoIterator<Item = Result<AllGameData, LiveClientError>>) -> Self {
+        Self(Mutex::new(polls.into_iter().collect()))
+    }
+}
+
+impl GameDataSource for Scripted {
+    async fn poll_game_data(&self) -> Result<AllGameData, LiveClientError> {
+        self.0.lock().unwrap().pop_front().expect("the script ran out")
+    }
+
+    async fn get_game_version(&self) -> Result<String, LiveClientError> {
+        Ok("15.22.1.1".to_string())
+    }
+
+    async fn get_active_player_name(&self) -> Result<String, LiveClientError> {
+        Err(LiveClientError::Status(404))
+    }
+}
+
+fn in_game() -> Result<AllGameData, LiveClientError> {
+    let text = std::fs::read_to_string(fixtures().join("live_client/allgamedata_mayhem.json")).unwrap();
+    Ok(serde_json::from_str(&text).unwrap())
+}
+
+#[tokio::test]
+async fn server_errors_mid_game_do_not_end_it() {
+    let source = Scripted::new([
+        in_game(),
+        Err(LiveClientError::Status(500)),
+        Err(LiveClientError::Status(503)),
+        Err(LiveClientError::Timeout),
+        in_game(),
+    ]);
+    let mut tracker = GameTracker::new(&AUGMENT_LEVELS, Vec::new());
+
+    assert_eq!(tracker.poll(&source).await.event, PollEvent::Started);
+    for _ in 0..3 {
+        let result = tracker.poll(&source).await;
+        assert_eq!(result.event, PollEvent::Idle);
+        assert!(result.error.is_some());
+        assert!(tracker.game().is_some());
+    }
+    assert_eq!(tracker.poll(&source).await.event, PollEvent::InGame);
+}
+
+#[tokio::test]
+async fn only_a_run_of_not_found_ends_the_game() {
+    let not_found = || Err(LiveClientError::Status(404));
+    let mut script = vec![in_game(), not_found(), in_game()];
+    script.extend((0..END_AFTER_NOT_FOUND).map(|_| not_found()));
+    let source = Scripted::new(script);
+    let mut tracker = GameTracker::new(&AUGMENT_LEVELS, Vec::new());
+
+    assert_eq!(tracker.poll(&source).await.event, PollEvent::Started);
+    // A lone 404 is forgotten once the game answers again.
+    assert_eq!(tracker.poll(&source).await.event, PollEvent::Idle);
+    assert_eq!(tracker.poll(&source).await.event, PollEvent::InGame);
+
+    for _ in 1..END_AFTER_NOT_FOUND {
+        assert_eq!(tracker.poll(&source).await.event, PollEvent::Idle);
+        assert!(tracker.game().is_some());
+    }
+    assert_eq!(tracker.poll(&source).await.event, PollEvent::Ended);
+    assert!(tracker.game().is_none());
+}
+
+#[tokio::test]
+async fn keeps_scanning_slowly_for_a_late_pick() {
+    let mut late = in_game().unwrap();
+    late.game_data.game_time += 600.0;
+    let source = Scripted::new([in_game(), Ok(late)]);
+    let mut tracker = GameTracker::new(&AUGMENT_LEVELS, Vec::new());
+
+    tracker.poll(&source).await;
+    assert_eq!(tracker.game().unwrap().capture_interval(), Some(CAPTURE_INTERVAL));
+
+    // Level 9 reached two augment levels long ago and neither pick was read.
+    tracker.poll(&source).await;
+    let game = tracker.game_mut().unwrap();
+    assert!(!game.is_window_open());
+    assert_eq!(game.capture_interval(), Some(FALLBACK_CAPTURE_INTERVAL));
+
+    // A slow scan that finds cards speeds back up to read them.
+    game.session.observe(Some([Some(1), Some(2), Some(3)]));
+    assert_eq!(game.capture_interval(), Some(CAPTURE_INTERVAL));
+    for _ in 1..3 {
+        game.session.observe(Some([Some(1), Some(2), Some(3)]));
+    }
+    for _ in 0..CLOSE_FRAMES {
+        game.session.observe(None);
+    }
+    assert_eq!(game.session.picks().len(), 1);
+    assert_eq!(game.capture_interval(), Some(FALLBACK_CAPTURE_INTERVAL));
+}
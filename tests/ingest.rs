@@ -0,0 +1,127 @@
+use aram_mayhem_advisor::data::{MayhemData, Rarity};
+use aram_mayhem_advisor::ingest::{build_mayhem_data, read_rows, AugmentStatsRow, BuildOptions, ChampionStatsRow, IngestError, SynergyStatsRow};
+
+fn names(count: i32) -> MayhemData {
+    let augments: Vec<String> =
+        (1..=count).map(|id| format!(r#"{{ "id": {}, "name": {{ "ko_kr": "증강 {}" }} }}"#, id, id)).collect();
+    serde_json::from_str(&format!(r#"{{ "champions": {{}}, "augments": [{}] }}"#, augments.join(","))).unwrap()
+}
+
+fn augment(id: i32, games: i32, wins: i32) -> AugmentStatsRow {
+    AugmentStatsRow { id, games, wins, picks: None, rarity: None }
+}
+
+fn champion(champion: &str, id: i32, games: i32, wins: i32) -> ChampionStatsRow {
+    ChampionStatsRow { champion: champion.to_string(), id, games, wins, picks: None }
+}
+
+fn pair(a: i32, b: i32, games: i32, wins: i32) -> SynergyStatsRow {
+    SynergyStatsRow { a, b, games, wins }
+}
+
+fn tier(data: &MayhemData, id: i32) -> Option<&str> {
+    data.augments.iter().find(|a| a.id == id).unwrap().tier.as_deref()
+}
+
+#[test]
+fn tiers_by_win_rate_percentile() {
+    // Augment 1 wins most, 10 least.
+    let rows: Vec<_> = (1..=10).map(|id| augment(id, 1000, 600 - id * 10)).collect();
+    let data = build_mayhem_data(&names(10), &rows, &[], &[], &BuildOptions::default()).unwrap();
+
+    let tiers: Vec<_> = (1..=10).map(|id| tier(&data, id).unwrap()).collect();
+    assert_eq!(tiers, ["S+", "S", "A", "A", "A", "B", "B", "C", "C", "D"]);
+    assert_eq!(data.augments[0].win_rate, Some(0.59));
+    assert_eq!(data.augments[0].popularity.as_deref(), Some("10.00%"));
+}
+
+#[test]
+fn small_perfect_samples_do_not_top_the_tiers() {
+    // A single won game, a short lucky streak and a large sample that wins a little less often.
+    let mut rows: Vec<_> = (1..=8).map(|id| augment(id, 10_000, 5_000 - id * 50)).collect();
+    rows.push(augment(9, 1, 1));
+    rows.push(augment(10, 120, 90));
+    rows.push(augment(11, 20_000, 14_400));
+    let data = build_mayhem_data(&names(11), &rows, &[], &[], &BuildOptions::default()).unwrap();
+
+    assert_eq!(tier(&data, 9), None);
+    assert_eq!(tier(&data, 11), Some("S+"));
+    assert_eq!(tier(&data, 10), Some("S"));
+    assert_eq!(data.augments[9].win_rate, Some(0.75));
+}
+
+#[test]
+fn leaves_small_samples_unranked() {
+    let rows = [augment(1, 500, 200), augment(2, 500, 300), augment(3, 10, 9)];
+    let options = BuildOptions { min_games: 100, ..BuildOptions::default() };
+    let data = build_mayhem_data(&names(3), &rows, &[champion("Lux", 3, 10, 9)], &[], &options).unwrap();
+
+    // Of two ranked augments the better one sits at the 50th percentile.
+    assert_eq!(tier(&data, 2), Some("A"));
+    assert_eq!(tier(&data, 1), Some("D"));
+    assert_eq!(tier(&data, 3), None);
+    assert_eq!(data.augments[2].games, Some(10));
+    // A champion with nothing ranked gets no entry at all.
+    assert!(!data.champions.contains_key("lux"));
+}
+
+#[test]
+fn rejects_duplicate_rows() {
+    let result = build_mayhem_data(&names(2), &[augment(1, 10, 5), augment(1, 20, 5)], &[], &[], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::DuplicateAugment(1))));
+
+    let champions = [champion("Lux", 2, 10, 5), champion(" lux", 2, 30, 20)];
+    let result = build_mayhem_data(&names(2), &[], &champions, &[], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::DuplicateChampionAugment(ref c, 2)) if c == "lux"));
+
+    // The same augment for two champions is fine.
+    let champions = [champion("Lux", 2, 10, 5), champion("Jinx", 2, 30, 20)];
+    assert!(build_mayhem_data(&names(2), &[], &champions, &[], &BuildOptions::default()).is_ok());
+}
+
+#[test]
+fn rejects_unknown_augments_and_impossible_rows() {
+    let result = build_mayhem_data(&names(1), &[augment(5, 10, 5)], &[], &[], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::UnknownAugment(5))));
+
+    let result = build_mayhem_data(&names(1), &[augment(1, 10, 11)], &[], &[], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::InvalidRow(_))));
+}
+
+#[test]
+fn tiers_synergies_among_pairs() {
+    let rows = [pair(2, 1, 1000, 700), pair(1, 3, 1000, 400), pair(2, 3, 10, 10)];
+    let options = BuildOptions { min_games: 100, ..BuildOptions::default() };
+    let data = build_mayhem_data(&names(3), &[], &[], &rows, &options).unwrap();
+
+    // The small pair is left out and the rest are stored in ascending order.
+    assert_eq!(data.synergies.len(), 2);
+    assert_eq!(data.synergies[0].augments, [1, 2]);
+    assert_eq!(data.synergies[0].tier, "A");
+    assert_eq!(data.synergies[0].win_rate, Some(0.7));
+    assert_eq!(data.synergy(1, 3).unwrap().tier, "D");
+}
+
+#[test]
+fn rejects_bad_synergy_rows() {
+    let result = build_mayhem_data(&names(2), &[], &[], &[pair(1, 2, 10, 5), pair(2, 1, 20, 5)], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::DuplicateSynergy(1, 2))));
+
+    let result = build_mayhem_data(&names(2), &[], &[], &[pair(1, 1, 10, 5)], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::InvalidRow(_))));
+
+    let result = build_mayhem_data(&names(2), &[], &[], &[pair(1, 9, 10, 5)], &BuildOptions::default());
+    assert!(matches!(result, Err(IngestError::UnknownAugment(9))));
+}
+
+#[test]
+fn takes_rarities_from_the_export() {
+    let path = std::env::temp_dir().join(format!("aram-mayhem-ingest-{}.csv", std::process::id()));
+    std::fs::write(&path, "id,games,wins,picks,rarity\n1,100,60,,prismatic\n2,100,40,,\n").unwrap();
+    let rows: Vec<AugmentStatsRow> = read_rows(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let data = build_mayhem_data(&names(2), &rows, &[], &[], &BuildOptions::default()).unwrap();
+    assert_eq!(data.augments[0].rarity, Some(Rarity::Prismatic));
+    assert_eq!(data.augments[1].rarity, None);
+}
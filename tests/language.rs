@@ -0,0 +1,60 @@
+use aram_mayhem_advisor::data::{Language, Name};
+
+#[test]
+fn parses_codes_keys_and_dashes() {
+    assert_eq!("ko_KR".parse(), Ok(Language::KoKr));
+    assert_eq!("ko_kr".parse(), Ok(Language::KoKr));
+    assert_eq!(" en-GB ".parse(), Ok(Language::EnGb));
+    assert!("ko".parse::<Language>().is_err());
+    assert!("xx_XX".parse::<Language>().is_err());
+}
+
+#[test]
+fn every_language_round_trips_through_its_code() {
+    for lang in Language::ALL {
+        assert_eq!(lang.key(), lang.code().to_lowercase());
+        assert_eq!(lang.to_string().parse(), Ok(lang));
+        assert_eq!(lang.key().parse(), Ok(lang));
+    }
+}
+
+#[test]
+fn fallback_chains_end_in_english() {
+    let chain = |lang: Language| lang.fallback_chain().collect::<Vec<_>>();
+
+    assert_eq!(chain(Language::EsAr), [Language::EsAr, Language::EsMx, Language::EsEs, Language::EnGb]);
+    assert_eq!(chain(Language::ZhTw), [Language::ZhTw, Language::ZhCn, Language::EnGb]);
+    assert_eq!(chain(Language::EnAu), [Language::EnAu, Language::EnGb]);
+    assert_eq!(chain(Language::EnGb), [Language::EnGb]);
+    assert_eq!(chain(Language::KoKr), [Language::KoKr, Language::EnGb]);
+
+    for lang in Language::ALL {
+        let chain = chain(lang);
+        assert_eq!(chain.first(), Some(&lang));
+        assert_eq!(chain.last(), Some(&Language::EnGb));
+    }
+}
+
+#[test]
+fn names_fall_back_past_missing_and_blank_translations() {
+    let name: Name = serde_json::from_str(r#"{ "es_mx": " ", "es_es": "Uno", "en_gb": "One" }"#).unwrap();
+
+    assert_eq!(name.get(Language::EsAr), "Uno");
+    assert_eq!(name.get(Language::KoKr), "One");
+    assert_eq!(name.get_exact(Language::EsMx), None);
+    assert_eq!(Name::default().get(Language::KoKr), "");
+}
+
+#[test]
+fn languages_and_names_round_trip_through_serde() {
+    for lang in Language::ALL {
+        let json = serde_json::to_string(&lang).unwrap();
+        assert_eq!(json, format!("\"{}\"", lang.key()));
+        assert_eq!(serde_json::from_str::<Language>(&json).unwrap(), lang);
+    }
+    assert!(serde_json::from_str::<Language>("\"xx_xx\"").is_err());
+
+    let name: Name = serde_json::from_str(r#"{ "ko_kr": "하나", "en_gb": "One" }"#).unwrap();
+    let json = serde_json::to_string(&name).unwrap();
+    assert_eq!(serde_json::from_str::<Name>(&json).unwrap(), name);
+}
@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use aram_mayhem_advisor::live_client::{AllGameData, Backoff, LiveClient};
+use aram_mayhem_advisor::teamcomp::TeamComp;
+
+fn fixture(name: &str) -> AllGameData {
+    let path = format!("{}/tests/fixtures/live_client/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+#[test]
+fn parses_the_active_player() {
+    let game = fixture("allgamedata_mayhem.json");
+    let me = &game.active_player;
+
+    assert_eq!(me.level, 9);
+    assert_eq!(me.current_gold, 1245.7);
+    assert_eq!(me.riot_id_game_name.as_deref(), Some("Hide on bush"));
+    assert_eq!(me.riot_id_tag_line.as_deref(), Some("KR1"));
+    assert_eq!(me.abilities.q.ability_level, Some(5));
+    assert_eq!(me.abilities.passive.ability_level, None);
+    assert_eq!(me.abilities.r.id, "VayneInquisition");
+    assert_eq!(me.champion_stats.attack_range, 550.0);
+    assert_eq!(me.champion_stats.resource_type, "MANA");
+    assert_eq!(me.full_runes.keystone.id, 8008);
+    assert_eq!(me.full_runes.stat_runes.len(), 3);
+}
+
+#[test]
+fn parses_players_items_and_scores() {
+    let game = fixture("allgamedata_mayhem.json");
+    assert_eq!(game.all_players.len(), 10);
+
+    let me = LiveClient::get_my_player(&game).unwrap();
+    assert_eq!(me.level, 9);
+    assert_eq!(me.items.len(), 2);
+    assert_eq!(me.items[0].item_id, 3153);
+    assert_eq!(me.items[1].slot, 1);
+    assert_eq!((me.scores.kills, me.scores.deaths, me.scores.assists, me.scores.creep_score), (3, 1, 5, 42));
+    assert_eq!(me.summoner_spells.summoner_spell_one.raw_display_name, "GeneratedTip_SummonerSpell_SummonerSnowball_DisplayName");
+    assert_eq!(me.runes.primary_rune_tree.id, 8000);
+
+    let talon = game.all_players.iter().find(|p| p.raw_champion_name.ends_with("Talon")).unwrap();
+    assert!(talon.is_dead);
+    assert_eq!(talon.respawn_timer, 12.5);
+}
+
+#[test]
+fn parses_events_and_game_data() {
+    let game = fixture("allgamedata_mayhem.json");
+    let events = &game.events.events;
+
+    assert_eq!(events.len(), 5);
+    assert_eq!(events[0].name, "GameStart");
+    assert_eq!(events[3].name, "ChampionKill");
+    assert_eq!(events[3].time, 95.3);
+    assert_eq!(events[3].extra["VictimName"], "정의");
+
+    assert!(LiveClient::is_mayhem_mode(&game));
+    assert_eq!(game.game_data.map_number, 12);
+    assert_eq!(LiveClient::get_my_champion(&game).as_deref(), Some("vayne"));
+
+    let comp = TeamComp::from_game(&game).unwrap();
+    assert_eq!(comp.allies, ["lux", "garen", "soraka", "jinx"]);
+    assert_eq!(comp.enemies, ["zed", "talon", "malphite", "ezreal", "nami"]);
+}
+
+#[test]
+fn tolerates_missing_and_unknown_fields() {
+    let game = fixture("allgamedata_legacy.json");
+
+    assert_eq!(game.active_player.level, 0);
+    assert!(game.active_player.riot_id.is_none());
+    assert!(game.events.events.is_empty());
+    assert!(game.all_players[0].items.is_empty());
+    assert!(!LiveClient::is_mayhem_mode(&game));
+    assert_eq!(LiveClient::get_my_champion(&game).as_deref(), Some("lux"));
+}
+
+#[test]
+fn empty_payload_has_no_player() {
+    let game: AllGameData =
+        serde_json::from_str(r#"{ "allPlayers": [], "gameData": { "gameMode": "KIWI", "gameTime": 0.0 } }"#).unwrap();
+    assert!(LiveClient::get_my_player(&game).is_none());
+}
+
+#[test]
+fn requires_the_players_and_game_mode() {
+    assert!(serde_json::from_str::<AllGameData>("{}").is_err());
+    assert!(serde_json::from_str::<AllGameData>(r#"{ "allPlayers": [], "gameData": { "mode": "KIWI", "gameTime": 0.0 } }"#).is_err());
+    assert!(serde_json::from_str::<AllGameData>(r#"{ "gameData": { "gameMode": "KIWI", "gameTime": 0.0 } }"#).is_err());
+}
+
+#[test]
+fn matches_the_active_player_by_riot_id() {
+    let game = fixture("allgamedata_riot_id.json");
+
+    assert_eq!(game.active_player.identities(), ["별빛#KR2"]);
+    assert_eq!(LiveClient::get_my_champion(&game).as_deref(), Some("lux"));
+    assert_eq!(TeamComp::from_game(&game).unwrap().allies, ["jinx"]);
+
+    // The game name alone is shared by two players.
+    assert!(LiveClient::find_player(&game, "별빛").is_none());
+    assert_eq!(LiveClient::find_player(&game, "그림자#kr1").map(LiveClient::champion_id).as_deref(), Some("zed"));
+}
+
+#[test]
+fn falls_back_to_the_active_player_name_endpoint() {
+    let mut game = fixture("allgamedata_riot_id.json");
+    game.active_player = Default::default();
+    assert!(LiveClient::get_my_player(&game).is_none());
+
+    game.active_player.riot_id = Some("별빛#KR1".to_string());
+    assert_eq!(LiveClient::get_my_champion(&game).as_deref(), Some("jinx"));
+}
+
+#[test]
+fn backoff_doubles_up_to_the_limit() {
+    let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+
+    backoff.fail();
+    assert_eq!(backoff.delay(), Duration::from_secs(2));
+    backoff.fail();
+    backoff.fail();
+    assert_eq!(backoff.delay(), Duration::from_secs(5));
+
+    backoff.reset();
+    assert_eq!(backoff.delay(), Duration::from_secs(1));
+}
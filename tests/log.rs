@@ -0,0 +1,20 @@
+use aram_mayhem_advisor::log::append;
+use aram_mayhem_advisor::metadata::parse_date;
+
+#[test]
+fn appends_timestamped_lines() {
+    let path = std::env::temp_dir().join(format!("aram-mayhem-log-{}", std::process::id())).join("advisor.log");
+    let _ = std::fs::remove_file(&path);
+
+    append(&path, "first").unwrap();
+    append(&path, "second\nwith a body").unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with('[') && lines[0].ends_with("] first"), "{}", lines[0]);
+    assert!(parse_date(&lines[0][1..11]).is_some(), "{}", lines[0]);
+    assert_eq!(lines[2], "with a body");
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
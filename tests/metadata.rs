@@ -0,0 +1,62 @@
+use aram_mayhem_advisor::metadata::{format_date, parse_date, patch_prefix, DataMetadata, DataWarning};
+
+#[test]
+fn parses_dates_as_days_since_the_epoch() {
+    assert_eq!(parse_date("1970-01-01"), Some(0));
+    assert_eq!(parse_date("1969-12-31"), Some(-1));
+    assert_eq!(parse_date("2000-03-01"), Some(11_017));
+}
+
+#[test]
+fn accepts_only_the_exact_date_shape() {
+    for date in ["2025-11-01xyz", "2025-11-02T10:00:00Z", " 2025-11-02", "2025-11-2", "25-11-02", "2025/11/02", "+025-11-02", "2025-1-012"] {
+        assert!(parse_date(date).is_none(), "{}", date);
+    }
+}
+
+#[test]
+fn rejects_days_past_the_end_of_the_month() {
+    assert!(parse_date("2024-02-29").is_some());
+    assert!(parse_date("2000-02-29").is_some());
+    assert!(parse_date("2025-02-29").is_none());
+    assert!(parse_date("1900-02-29").is_none());
+    assert!(parse_date("2025-04-31").is_none());
+    assert!(parse_date("2025-12-31").is_some());
+    assert!(parse_date("2025-13-01").is_none());
+    assert!(parse_date("2025-01-00").is_none());
+    assert!(parse_date("2025-1").is_none());
+    assert!(parse_date("soon").is_none());
+}
+
+#[test]
+fn formats_dates_back_from_days() {
+    for date in ["1970-01-01", "1969-12-31", "2000-02-29", "2025-12-31", "2026-10-18"] {
+        assert_eq!(format_date(parse_date(date).unwrap()), date);
+    }
+}
+
+#[test]
+fn reads_the_patch_prefix() {
+    assert_eq!(patch_prefix("15.22"), Some((15, 22)));
+    assert_eq!(patch_prefix("15.22.719.4137"), Some((15, 22)));
+    assert_eq!(patch_prefix(" 15.3 "), Some((15, 3)));
+    assert_eq!(patch_prefix("15"), None);
+    assert_eq!(patch_prefix("v15.22"), None);
+}
+
+#[test]
+fn warns_about_another_patch_and_old_data() {
+    let metadata = DataMetadata {
+        patch: Some("15.22".to_string()),
+        collected_to: Some("2025-11-01".to_string()),
+        ..DataMetadata::default()
+    };
+    let today = parse_date("2025-11-11").unwrap();
+
+    assert!(metadata.check(Some("15.22.719.4137"), today, 14).is_empty());
+    assert_eq!(metadata.check(Some("15.23.1.1"), today, 14), [DataWarning::PatchMismatch {
+        data: "15.22".to_string(),
+        game: "15.23".to_string(),
+    }]);
+    assert_eq!(metadata.check(None, today, 7), [DataWarning::Stale { days: 10 }]);
+}
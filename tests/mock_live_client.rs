@@ -0,0 +1,89 @@
+mod common;
+
+use aram_mayhem_advisor::live_client::{GameDataSource, LiveClient, LiveClientError};
+use aram_mayhem_advisor::tls::{TlsError, TlsMode};
+
+use common::{eventually, fixtures, pinned, start_mock, write_timeline, MockServer};
+
+// Loading for `loading` seconds, in game for 5s, then the server goes away.
+fn start(name: &str, loading: f64) -> MockServer {
+    let game = fixtures().join("live_client/allgamedata_mayhem.json");
+    let timeline = write_timeline(name, serde_json::json!([
+        { "seconds": loading },
+        { "seconds": 5.0, "fixture": game, "game_time": 100.0 }
+    ]));
+    start_mock(&timeline, 1.0)
+}
+
+#[tokio::test]
+async fn plays_out_a_game_timeline() {
+    let server = start("timeline", 3.0);
+    let client = LiveClient::with_tls(&server.url, &pinned("mock-ca.crt")).unwrap();
+
+    assert!(matches!(client.poll_game_data().await, Err(LiveClientError::Status(404))));
+    assert!(client.get_game_version().await.is_err());
+    assert!(client.get_active_player_name().await.is_err());
+
+    let game = eventually("the game to load", async || client.poll_game_data().await.ok()).await;
+    assert!(LiveClient::is_mayhem_mode(&game));
+    assert_eq!(LiveClient::get_my_champion(&game).as_deref(), Some("vayne"));
+    assert!(game.game_data.game_time >= 100.0);
+    assert_eq!(client.get_game_version().await.unwrap(), "15.22.1.1");
+    assert_eq!(client.get_active_player_name().await.unwrap(), "Hide on bush#KR1");
+
+    eventually("game time to advance", async || {
+        let later = client.poll_game_data().await.ok()?;
+        (later.game_data.game_time > game.game_data.game_time).then_some(())
+    })
+    .await;
+
+    eventually("the server to close", async || {
+        matches!(client.poll_game_data().await, Err(LiveClientError::NotRunning(_))).then_some(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn only_trusts_the_pinned_root() {
+    let server = start("tls", 0.0);
+
+    let trusted = LiveClient::with_tls(&server.url, &pinned("mock-ca.crt")).unwrap();
+    assert!(trusted.poll_game_data().await.is_ok());
+
+    let unrelated = LiveClient::with_tls(&server.url, &pinned("other-ca.crt")).unwrap();
+    assert!(matches!(unrelated.poll_game_data().await, Err(LiveClientError::Tls(_))));
+
+    let insecure = LiveClient::with_tls(&server.url, &TlsMode::Insecure).unwrap();
+    assert!(insecure.poll_game_data().await.is_ok());
+}
+
+#[test]
+fn pinning_needs_a_certificate() {
+    assert!(LiveClient::with_tls("https://127.0.0.1:2999", &TlsMode::Pinned(b"not a certificate".to_vec())).is_err());
+}
+
+#[test]
+#[cfg_attr(not(riot_root_ca), ignore = "data/riotgames.pem is missing")]
+fn embeds_the_riot_root() {
+    assert!(matches!(TlsMode::RiotRoot.client_config(), Ok(Some(_))));
+}
+
+#[test]
+#[cfg_attr(riot_root_ca, ignore = "data/riotgames.pem is present")]
+fn reports_a_missing_riot_root() {
+    assert!(matches!(TlsMode::RiotRoot.client_config(), Err(TlsError::MissingRiotRoot)));
+    assert!(LiveClient::with_tls("https://127.0.0.1:2999", &TlsMode::RiotRoot).is_err());
+}
+
+#[tokio::test]
+async fn keeps_the_body_of_a_schema_mismatch() {
+    let fixture = std::env::temp_dir().join(format!("aram-mayhem-schema-fixture-{}.json", std::process::id()));
+    std::fs::write(&fixture, r#"{ "gameData": { "gameMode": "KIWI", "gameTime": "soon" } }"#).unwrap();
+    let server = start_mock(&write_timeline("schema", serde_json::json!([{ "seconds": 5.0, "fixture": fixture }])), 1.0);
+
+    let client = LiveClient::with_tls(&server.url, &pinned("mock-ca.crt")).unwrap();
+    match client.poll_game_data().await {
+        Err(LiveClientError::Schema { body, .. }) => assert!(body.contains("\"soon\"")),
+        other => panic!("expected a schema error, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,42 @@
+use aram_mayhem_advisor::overlay::{update_script, AugmentDisplay};
+use aram_mayhem_advisor::preferences::Mark;
+
+fn card(note: &str) -> AugmentDisplay {
+    AugmentDisplay {
+        id: 1,
+        name: "하나".to_string(),
+        tier: "A".to_string(),
+        popularity: "10%".to_string(),
+        games: 100,
+        win_rate: Some(0.55),
+        champion: None,
+        synergies: Vec::new(),
+        score: Some(4.0),
+        best: true,
+        reroll: None,
+        mark: Some(Mark::Favorite),
+        note: Some(note.to_string()),
+        explanations: vec![format!("규칙: {}", note)],
+        data_label: None,
+        data_warning: None,
+    }
+}
+
+// Reads the argument back the way the page does: a JS string literal, then `JSON.parse`.
+fn argument(script: &str) -> serde_json::Value {
+    let literal = script.strip_prefix("window.updateCard(").and_then(|s| s.strip_suffix(");")).unwrap();
+    let json: String = serde_json::from_str(literal).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn passes_notes_through_unchanged() {
+    let note = "say \"hi\" \\t isn't\nC:\\games\\lol </script>";
+    let script = update_script(&card(note));
+
+    assert!(!script.contains('\n'));
+    let data = argument(&script);
+    assert_eq!(data["note"], note);
+    assert_eq!(data["explanations"][0], format!("규칙: {}", note));
+    assert_eq!(data["mark"], "favorite");
+}
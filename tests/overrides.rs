@@ -0,0 +1,87 @@
+use aram_mayhem_advisor::data::{Language, MayhemData, Rarity};
+use aram_mayhem_advisor::store::{apply_overrides, DataOverrides, DataStore, OverrideError};
+
+fn base() -> MayhemData {
+    serde_json::from_str(
+        r#"{
+            "champions": { "lux": [{ "id": 1, "tier": "A", "popularity": "10%", "games": 100 }] },
+            "augments": [{ "id": 1, "name": { "ko_kr": "하나", "en_gb": "One" }, "tier": "B", "games": 50 }],
+            "synergies": [{ "augments": [1, 2], "tier": "A", "games": 30 }]
+        }"#,
+    )
+    .unwrap()
+}
+
+fn overrides(text: &str) -> DataOverrides {
+    serde_json::from_str(text).unwrap()
+}
+
+#[test]
+fn patches_only_the_given_fields() {
+    let data = apply_overrides(&base(), &overrides(
+        r#"{ "augments": [{ "id": 1, "name": { "ko_kr": "첫째" }, "tier": "S", "rarity": "gold" }] }"#,
+    ))
+    .unwrap();
+
+    let augment = &data.augments[0];
+    assert_eq!(augment.name.get(Language::KoKr), "첫째");
+    assert_eq!(augment.name.get(Language::EnGb), "One");
+    assert_eq!(augment.tier.as_deref(), Some("S"));
+    assert_eq!(augment.games, Some(50));
+    assert_eq!(augment.rarity, Some(Rarity::Gold));
+}
+
+#[test]
+fn adds_new_augments_and_rejects_nameless_ones() {
+    let data = apply_overrides(&base(), &overrides(r#"{ "augments": [{ "id": 7, "name": { "ko_kr": "일곱" } }] }"#)).unwrap();
+    assert_eq!(data.augments.len(), 2);
+
+    let result = apply_overrides(&base(), &overrides(r#"{ "augments": [{ "id": 8, "tier": "S" }] }"#));
+    assert!(matches!(result, Err(OverrideError::MissingName(8))));
+}
+
+#[test]
+fn replaces_champion_stats_and_synergies_by_key() {
+    let data = apply_overrides(&base(), &overrides(
+        r#"{
+            "champions": { "Lux": [
+                { "id": 1, "tier": "S", "popularity": "12%", "games": 120 },
+                { "id": 2, "tier": "C", "popularity": "1%", "games": 8 }
+            ] },
+            "synergies": [{ "augments": [2, 1], "tier": "S", "games": 40 }],
+            "metadata": { "source": "test" }
+        }"#,
+    ))
+    .unwrap();
+
+    let lux = &data.champions["lux"];
+    assert_eq!(lux.len(), 2);
+    assert_eq!(lux[0].tier, "S");
+    assert_eq!(data.synergies.len(), 1);
+    assert_eq!(data.synergies[0].tier, "S");
+    assert!(data.metadata.is_some());
+}
+
+#[test]
+fn reloading_bumps_the_generation() {
+    let dir = std::env::temp_dir().join(format!("aram-mayhem-overrides-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("overrides.json");
+    std::fs::write(&path, r#"{ "augments": [{ "id": 1, "tier": "S" }] }"#).unwrap();
+
+    let store = DataStore::new(base(), Some(path.clone()));
+    let (generation, data) = store.snapshot();
+    assert_eq!(data.augments[0].tier.as_deref(), Some("S"));
+
+    std::fs::write(&path, "{ broken").unwrap();
+    assert!(!store.reload());
+    assert_eq!(store.snapshot().0, generation);
+
+    std::fs::write(&path, r#"{ "augments": [{ "id": 1, "tier": "C" }] }"#).unwrap();
+    assert!(store.reload());
+    let (reloaded, data) = store.snapshot();
+    assert_eq!(reloaded, generation + 1);
+    assert_eq!(data.augments[0].tier.as_deref(), Some("C"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
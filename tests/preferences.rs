@@ -0,0 +1,104 @@
+mod common;
+
+use std::collections::{BTreeMap, HashMap};
+
+use aram_mayhem_advisor::data::{Augment, Language, MayhemData, Name};
+use aram_mayhem_advisor::preferences::{Mark, Preference, Preferences};
+use aram_mayhem_advisor::recommend::{CardInput, Recommender};
+
+use common::augment;
+
+fn named(id: i32, ko: &str, en: &str) -> Augment {
+    let name = Name(BTreeMap::from([(Language::KoKr, ko.into()), (Language::EnGb, en.into())]));
+    Augment { name, ..augment(id, "B") }
+}
+
+fn preferences() -> Preferences {
+    serde_json::from_str(
+        r#"{
+            "augments": {
+                "1": { "mark": "favorite", "note": "항상 좋음" },
+                "2": { "mark": "avoid" }
+            },
+            "champions": {
+                "vayne": { "1": { "mark": "avoid" }, "3": { "note": "치명타 빌드" } }
+            }
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn champion_entries_override_global_ones_per_field() {
+    let prefs = preferences();
+    let (one, three) = (augment(1, "B"), augment(3, "B"));
+
+    assert_eq!(prefs.get("lux", &one), Some(Preference { mark: Some(Mark::Favorite), note: Some("항상 좋음".to_string()) }));
+    assert_eq!(prefs.get("Vayne", &one), Some(Preference { mark: Some(Mark::Avoid), note: Some("항상 좋음".to_string()) }));
+    assert_eq!(prefs.get("vayne", &three), Some(Preference { mark: None, note: Some("치명타 빌드".to_string()) }));
+    assert_eq!(prefs.get("lux", &three), None);
+}
+
+#[test]
+fn marks_change_the_ranking() {
+    let prefs = preferences();
+    let recommender = Recommender::default();
+    let (a, b, c) = (augment(1, "B"), augment(2, "A"), augment(4, "B"));
+    let (pa, pb) = (prefs.get("lux", &a), prefs.get("lux", &b));
+
+    let scores = recommender.rank(&[
+        Some(CardInput::new(&a).with_preference(pa.as_ref())),
+        Some(CardInput::new(&b).with_preference(pb.as_ref())),
+        Some(CardInput::new(&c)),
+    ]);
+
+    assert_eq!(scores[0].as_ref().unwrap().preference, 1.0);
+    assert_eq!(scores[1].as_ref().unwrap().preference, -1.0);
+    assert_eq!(Recommender::best(&scores), Some(0));
+}
+
+#[test]
+fn names_in_any_language_work_as_keys() {
+    let prefs: Preferences = serde_json::from_str(
+        r#"{
+            "augments": { "마법 가속": { "mark": "favorite" } },
+            "champions": { "vayne": { " die another day ": { "mark": "avoid" } } }
+        }"#,
+    )
+    .unwrap();
+    let (haste, day) = (named(7, "마법 가속", "Magic Haste"), named(24, "불사의 날", "Die Another Day"));
+
+    assert_eq!(prefs.get("lux", &haste).and_then(|p| p.mark), Some(Mark::Favorite));
+    assert_eq!(prefs.get("vayne", &day).and_then(|p| p.mark), Some(Mark::Avoid));
+    assert_eq!(prefs.get("lux", &day), None);
+}
+
+#[test]
+fn check_reports_keys_that_name_no_augment() {
+    let data = MayhemData {
+        metadata: None,
+        champions: HashMap::new(),
+        augments: vec![named(7, "마법 가속", "Magic Haste").into(), named(24, "불사의 날", "Die Another Day").into()],
+        synergies: Vec::new(),
+    };
+
+    let ok: Preferences = serde_json::from_str(r#"{ "augments": { "magic haste": {}, "24": {} } }"#).unwrap();
+    assert!(ok.check(&data).is_ok());
+
+    let typo: Preferences = serde_json::from_str(r#"{ "champions": { "lux": { "마법 가속도": {} } } }"#).unwrap();
+    assert_eq!(typo.check(&data).unwrap_err().to_string(), "unknown augment \"마법 가속도\"");
+}
+
+#[test]
+fn loads_from_a_file() {
+    let path = std::env::temp_dir().join(format!("aram-mayhem-preferences-{}.json", std::process::id()));
+
+    std::fs::write(&path, r#"{ "augments": { "5": { "mark": "favorite" } } }"#).unwrap();
+    let loaded = Preferences::load_from(&path).unwrap();
+    assert_eq!(loaded.get("lux", &augment(5, "B")).and_then(|p| p.mark), Some(Mark::Favorite));
+
+    std::fs::write(&path, r#"{ "augments": { "5": { "mark": "maybe" } } }"#).unwrap();
+    assert!(Preferences::load_from(&path).is_err());
+
+    std::fs::remove_file(&path).ok();
+}
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use aram_mayhem_advisor::crop_card_frames;
+use aram_mayhem_advisor::data::Rarity;
+use aram_mayhem_advisor::rarity::{frame_rarity, offered_rarity, SeenRarities};
+use image::{DynamicImage, Rgb, RgbImage};
+
+fn sample(name: &str) -> DynamicImage {
+    image::open(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata/augments").join(name)).unwrap()
+}
+
+#[test]
+fn reads_the_rarity_off_every_sample() {
+    let mut checked = 0;
+    for entry in std::fs::read_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata/augments")).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        let expected = if name.contains("silver") {
+            Rarity::Silver
+        } else if name.contains("prismatic") {
+            Rarity::Prismatic
+        } else {
+            // The resolution samples are all gold offers.
+            Rarity::Gold
+        };
+
+        let frames = crop_card_frames(&sample(&name));
+        for frame in &frames {
+            assert_eq!(frame_rarity(frame), Some(expected), "{}", name);
+        }
+        assert_eq!(offered_rarity(&frames), Some(expected), "{}", name);
+        checked += 1;
+    }
+    assert!(checked >= 3);
+}
+
+#[test]
+fn ignores_what_is_not_a_card_frame() {
+    let background = DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 40, Rgb([30, 45, 80])));
+    assert_eq!(frame_rarity(&background), None);
+
+    // One readable frame is not enough to call the offer.
+    let gold = DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 40, Rgb([155, 128, 104])));
+    assert_eq!(offered_rarity(&[gold.clone(), background.clone(), background]), None);
+    assert_eq!(offered_rarity(&[gold.clone(), gold]), Some(Rarity::Gold));
+}
+
+#[test]
+fn keeps_seen_rarities_on_disk() {
+    let path = std::env::temp_dir().join(format!("aram-mayhem-rarities-{}", std::process::id())).join("rarities.json");
+
+    let mut seen = SeenRarities::new();
+    assert!(seen.record(12, Rarity::Prismatic));
+    assert!(!seen.record(12, Rarity::Prismatic));
+    seen.save_to(&path).unwrap();
+
+    let loaded = SeenRarities::load_from(&path).unwrap();
+    assert_eq!(loaded.get(12), Some(Rarity::Prismatic));
+    assert_eq!(loaded, seen);
+
+    std::fs::write(&path, "{ broken").unwrap();
+    assert!(SeenRarities::load_from(&path).is_err());
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
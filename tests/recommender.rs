@@ -0,0 +1,151 @@
+mod common;
+
+use aram_mayhem_advisor::data::{Champion, Rarity, Synergy};
+use aram_mayhem_advisor::recommend::{CardInput, Recommender, Weights};
+use aram_mayhem_advisor::stats::{ResolvedStats, StatsLevel};
+use aram_mayhem_advisor::teamcomp::CompRule;
+
+use common::augment;
+
+fn stats(level: StatsLevel, tier: &str, games: i32) -> ResolvedStats {
+    ResolvedStats {
+        level,
+        class: None,
+        stats: Champion { id: 0, tier: tier.to_string(), popularity: "1.00%".to_string(), games, win_rate: None },
+    }
+}
+
+fn synergy(tier: &str) -> Synergy {
+    Synergy { augments: [1, 2], tier: tier.to_string(), games: 500, win_rate: None }
+}
+
+#[test]
+fn global_tier_alone_is_the_score() {
+    let recommender = Recommender::default();
+    let a = augment(1, "S");
+    let score = recommender.score(&CardInput::new(&a));
+    assert_eq!(score.score, 5.0);
+}
+
+#[test]
+fn champion_tier_weight_grows_with_games() {
+    let recommender = Recommender::default();
+    let a = augment(1, "C");
+    let few = stats(StatsLevel::Champion, "S+", 10);
+    let many = stats(StatsLevel::Champion, "S+", 10_000);
+
+    let few = recommender.score(&CardInput::new(&a).with_stats(Some(&few)));
+    let many = recommender.score(&CardInput::new(&a).with_stats(Some(&many)));
+
+    assert!(few.score < 3.0, "{}", few.score);
+    assert!(many.score > 4.0, "{}", many.score);
+}
+
+#[test]
+fn class_fallback_counts_less_than_champion_stats() {
+    let recommender = Recommender::default();
+    let a = augment(1, "C");
+    let champion = stats(StatsLevel::Champion, "S", 1000);
+    let class = stats(StatsLevel::Class, "S", 1000);
+
+    let champion = recommender.score(&CardInput::new(&a).with_stats(Some(&champion)));
+    let class = recommender.score(&CardInput::new(&a).with_stats(Some(&class)));
+
+    assert!(champion.score > class.score);
+}
+
+#[test]
+fn global_level_stats_do_not_count_twice() {
+    let recommender = Recommender::default();
+    let a = augment(1, "B");
+    let global = stats(StatsLevel::Global, "B", 100_000);
+    let score = recommender.score(&CardInput::new(&a).with_stats(Some(&global)));
+    assert_eq!(score.score, 3.0);
+}
+
+#[test]
+fn synergy_is_a_bonus_and_rarity_is_not() {
+    let weights = Weights { synergy: 1.0, ..Weights::default() };
+    let recommender = Recommender::new(weights);
+
+    let mut a = augment(1, "B");
+    a.rarity = Some(Rarity::Prismatic);
+    let s = synergy("S+");
+    let synergies = [&s];
+
+    let score = recommender.score(&CardInput::new(&a).with_synergies(&synergies));
+    assert_eq!(score.tier, 3.0);
+    assert_eq!(score.synergy, 3.0);
+    // The three offered cards always share a rarity, so it cannot change which one is best.
+    assert_eq!(score.score, 6.0);
+}
+
+#[test]
+fn the_weakest_passing_synergy_still_adds() {
+    let weights = Weights { synergy: 1.0, ..Weights::default() };
+    let recommender = Recommender::new(weights);
+
+    let a = augment(1, "B");
+    let s = synergy("A");
+    let synergies = [&s];
+
+    let score = recommender.score(&CardInput::new(&a).with_synergies(&synergies));
+    assert_eq!(score.synergy, 1.0);
+    assert!(score.score > recommender.score(&CardInput::new(&a)).score);
+}
+
+#[test]
+fn zero_weights_disable_components() {
+    let weights = Weights { synergy: 0.0, champion_tier: 0.0, ..Weights::default() };
+    let recommender = Recommender::new(weights);
+
+    let mut a = augment(1, "A");
+    a.rarity = Some(Rarity::Gold);
+    let champion = stats(StatsLevel::Champion, "D", 5000);
+    let s = synergy("S+");
+    let synergies = [&s];
+
+    let score = recommender.score(&CardInput::new(&a).with_stats(Some(&champion)).with_synergies(&synergies));
+    assert_eq!(score.score, 4.0);
+}
+
+#[test]
+fn best_picks_the_highest_score_and_leftmost_on_ties() {
+    let recommender = Recommender::default();
+    let (a, b, c) = (augment(1, "B"), augment(2, "S"), augment(3, "S"));
+
+    let scores = recommender.rank(&[
+        Some(CardInput::new(&a)),
+        Some(CardInput::new(&b)),
+        Some(CardInput::new(&c)),
+    ]);
+    assert_eq!(Recommender::best(&scores), Some(1));
+
+    let scores = recommender.rank(&[None, None, Some(CardInput::new(&a))]);
+    assert_eq!(Recommender::best(&scores), Some(2));
+    assert_eq!(Recommender::best(&[None, None, None]), None);
+}
+
+#[test]
+fn weights_deserialize_with_defaults() {
+    let weights: Weights = serde_json::from_str(r#"{ "synergy": 2.0 }"#).unwrap();
+    assert_eq!(weights.synergy, 2.0);
+    assert_eq!(weights.global_tier, Weights::default().global_tier);
+}
+
+#[test]
+fn team_comp_rules_only_adjust_listed_augments() {
+    let rules: Vec<CompRule> = serde_json::from_str(
+        r#"[{ "name": "burst", "when": { "classes": ["assassin"], "at_least": 2 }, "augments": [1], "adjust": 0.5 }]"#,
+    )
+    .unwrap();
+    let recommender = Recommender::default();
+    let (listed, other) = (augment(1, "B"), augment(2, "B"));
+
+    let listed = recommender.score(&CardInput::new(&listed).with_comp_rules(&rules));
+    let other = recommender.score(&CardInput::new(&other).with_comp_rules(&rules));
+
+    assert_eq!(listed.team, 0.5);
+    assert_eq!(listed.score, 3.5);
+    assert_eq!(other.team, 0.0);
+}
@@ -0,0 +1,148 @@
+use aram_mayhem_advisor::data::{MayhemData, Rarity, CHAMPIONS_DATA};
+use aram_mayhem_advisor::preferences::Preferences;
+use aram_mayhem_advisor::rarity::SeenRarities;
+use aram_mayhem_advisor::recommend::{CardInput, Recommender, Weights};
+use aram_mayhem_advisor::reroll::{RerollAdvisor, RerollInput};
+
+fn data(fourth_tier: &str) -> MayhemData {
+    serde_json::from_str(&format!(
+        r#"{{
+            "champions": {{}},
+            "augments": [
+                {{ "id": 1, "name": {{ "ko_kr": "하나" }}, "tier": "S", "rarity": "gold" }},
+                {{ "id": 2, "name": {{ "ko_kr": "둘" }}, "tier": "B", "rarity": "gold" }},
+                {{ "id": 3, "name": {{ "ko_kr": "셋" }}, "tier": "C", "rarity": "gold" }},
+                {{ "id": 4, "name": {{ "ko_kr": "넷" }}, "tier": "{}", "rarity": "gold" }},
+                {{ "id": 5, "name": {{ "ko_kr": "다섯" }}, "tier": "A", "rarity": "gold" }},
+                {{ "id": 6, "name": {{ "ko_kr": "여섯" }}, "tier": "D", "rarity": "silver" }},
+                {{ "id": 7, "name": {{ "ko_kr": "일곱" }}, "rarity": "gold" }},
+                {{ "id": 8, "name": {{ "ko_kr": "여덟" }}, "tier": "D" }}
+            ]
+        }}"#,
+        fourth_tier
+    ))
+    .unwrap()
+}
+
+fn score(recommender: &Recommender, data: &MayhemData, id: i32) -> f64 {
+    let augment = data.augments.iter().find(|a| a.id == id).unwrap();
+    recommender
+        .score(&CardInput::new(augment))
+        .score
+}
+
+static NOTHING_SEEN: SeenRarities = SeenRarities::new();
+
+fn input<'a>(data: &'a MayhemData, generation: u64, picked: &'a [i32], preferences: &'a Preferences) -> RerollInput<'a> {
+    RerollInput {
+        data,
+        generation,
+        champions: &CHAMPIONS_DATA,
+        champion: "lux",
+        picked,
+        comp_rules: &[],
+        score_rules: &[],
+        preferences,
+        offered: None,
+        seen: &NOTHING_SEEN,
+    }
+}
+
+#[test]
+fn expects_the_mean_of_the_same_rarity_pool() {
+    let recommender = Recommender::new(Weights::default());
+    let data = data("S");
+    let preferences = Preferences::default();
+    let mut advisor = RerollAdvisor::new();
+
+    let cards = [1, 2, 3].map(|id| Some((id, score(&recommender, &data, id))));
+    // 1–3 are shown and 5 is picked, so a gold reroll can only land on 4; 6 is silver and 7 has no tier.
+    let advice = advisor.advise(&recommender, &input(&data, 0, &[5], &preferences), cards);
+
+    let expected = score(&recommender, &data, 4);
+    for advice in &advice {
+        assert_eq!(advice.unwrap().expected, expected);
+    }
+    assert!(!advice[0].unwrap().better);
+    assert!(advice[1].unwrap().better);
+    assert!(advice[2].unwrap().better);
+}
+
+#[test]
+fn suggests_only_beyond_the_margin() {
+    let data = data("B");
+    let preferences = Preferences::default();
+    let cards = |recommender: &Recommender| [1, 2, 3].map(|id| Some((id, score(recommender, &data, id))));
+
+    // Rerolling the B card can only land on another B.
+    let recommender = Recommender::new(Weights::default());
+    let advice = RerollAdvisor::new().advise(&recommender, &input(&data, 0, &[5], &preferences), cards(&recommender));
+    assert!(!advice[1].unwrap().better);
+
+    let recommender = Recommender::new(Weights { reroll_margin: -0.1, ..Weights::default() });
+    let advice = RerollAdvisor::new().advise(&recommender, &input(&data, 0, &[5], &preferences), cards(&recommender));
+    assert!(advice[1].unwrap().better);
+}
+
+#[test]
+fn gives_no_advice_without_a_rarity() {
+    let recommender = Recommender::new(Weights::default());
+    let data = data("S");
+    let preferences = Preferences::default();
+
+    let advice = RerollAdvisor::new().advise(&recommender, &input(&data, 0, &[], &preferences), [Some((8, 0.0)), None, None]);
+    assert_eq!(advice, [None, None, None]);
+}
+
+#[test]
+fn rebuilds_the_pool_when_the_generation_changes() {
+    let recommender = Recommender::new(Weights::default());
+    let preferences = Preferences::default();
+    let mut advisor = RerollAdvisor::new();
+    let cards = [Some((3, 0.0)), None, None];
+
+    let before = data("S");
+    advisor.advise(&recommender, &input(&before, 0, &[1, 2, 5], &preferences), cards);
+
+    let after = data("D");
+    let cached = advisor.advise(&recommender, &input(&after, 0, &[1, 2, 5], &preferences), cards);
+    assert_eq!(cached[0].unwrap().expected, score(&recommender, &before, 4));
+
+    let rebuilt = advisor.advise(&recommender, &input(&after, 1, &[1, 2, 5], &preferences), cards);
+    assert_eq!(rebuilt[0].unwrap().expected, score(&recommender, &after, 4));
+}
+
+#[test]
+fn fills_missing_rarities_from_the_screen() {
+    let recommender = Recommender::new(Weights::default());
+    let data: MayhemData = serde_json::from_str(
+        r#"{
+            "champions": {},
+            "augments": [
+                { "id": 1, "name": { "ko_kr": "하나" }, "tier": "C" },
+                { "id": 2, "name": { "ko_kr": "둘" }, "tier": "S" },
+                { "id": 3, "name": { "ko_kr": "셋" }, "tier": "D" },
+                { "id": 4, "name": { "ko_kr": "넷" }, "tier": "D", "rarity": "silver" }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let preferences = Preferences::default();
+    let cards = [Some((1, score(&recommender, &data, 1))), None, None];
+
+    // Augment 2 was seen on a gold card before and 3 on a silver one; the data's own rarity wins for 4.
+    let mut seen = SeenRarities::new();
+    assert!(seen.record(2, Rarity::Gold));
+    assert!(seen.record(3, Rarity::Silver));
+    assert!(!seen.record(3, Rarity::Silver));
+    seen.record(4, Rarity::Gold);
+
+    let offered = RerollInput { offered: Some(Rarity::Gold), seen: &seen, ..input(&data, 0, &[], &preferences) };
+    let advice = RerollAdvisor::new().advise(&recommender, &offered, cards);
+    assert_eq!(advice[0].unwrap().expected, score(&recommender, &data, 2));
+    assert!(advice[0].unwrap().better);
+
+    // Neither the screen nor the data know the card's rarity.
+    let unread = RerollInput { seen: &seen, ..input(&data, 0, &[], &preferences) };
+    assert_eq!(RerollAdvisor::new().advise(&recommender, &unread, cards), [None, None, None]);
+}
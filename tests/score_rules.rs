@@ -0,0 +1,58 @@
+mod common;
+
+use aram_mayhem_advisor::data::CHAMPIONS_DATA;
+use aram_mayhem_advisor::recommend::{CardInput, Recommender};
+use aram_mayhem_advisor::rules::{active_score_rules, parse_score_rules, RuleContext};
+use aram_mayhem_advisor::teamcomp::TeamComp;
+
+use common::augment;
+
+fn context<'a>(champion: &'a str, picked: &'a [i32], comp: Option<&'a TeamComp>) -> RuleContext<'a> {
+    RuleContext { champion, picked, comp, champions: &CHAMPIONS_DATA }
+}
+
+#[test]
+fn game_filters_decide_which_rules_are_active() {
+    let rules = parse_score_rules(
+        "test",
+        r#"[
+            { "name": "vayne", "when": { "champions": ["Vayne"] }, "adjust": 1.0 },
+            { "name": "after-1", "when": { "picked": [1] }, "adjust": 1.0 },
+            { "name": "vs-assassins", "when": { "team": [{ "classes": ["assassin"], "at_least": 2 }] }, "adjust": 1.0 },
+            { "name": "always", "adjust": 1.0 }
+        ]"#,
+    )
+    .unwrap();
+    let comp = TeamComp { allies: Vec::new(), enemies: vec!["zed".to_string(), "talon".to_string()] };
+    let active = |ctx: &RuleContext| active_score_rules(&rules, ctx).into_iter().map(|r| r.name).collect::<Vec<_>>();
+
+    assert_eq!(active(&context("lux", &[], None)), ["always"]);
+    assert_eq!(active(&context("vayne", &[1], None)), ["vayne", "after-1", "always"]);
+    assert_eq!(active(&context("lux", &[], Some(&comp))), ["vs-assassins", "always"]);
+}
+
+#[test]
+fn card_filters_pick_the_adjusted_augments() {
+    let rules = parse_score_rules(
+        "test",
+        r#"[
+            { "name": "skip-c", "when": { "tiers": ["c", "D"] }, "adjust": -2.0 },
+            { "name": "likes-7", "when": { "augments": [7] }, "adjust": 0.5, "reason": { "ko_kr": "좋아하는 증강" } }
+        ]"#,
+    )
+    .unwrap();
+    let recommender = Recommender::default();
+    let (liked, low, other) = (augment(7, "B"), augment(8, "C"), augment(9, "B"));
+    let card = |a| CardInput::new(a).with_score_rules(&rules);
+
+    assert_eq!(recommender.score(&card(&liked)).custom, 0.5);
+    assert_eq!(recommender.score(&card(&low)).custom, -2.0);
+    assert_eq!(recommender.score(&card(&other)).custom, 0.0);
+    assert_eq!(recommender.explain(&card(&liked)).len(), 3);
+}
+
+#[test]
+fn broken_rules_report_the_source() {
+    let error = parse_score_rules("score_rules.json", r#"[{ "name": "typo", "adjust": 1.0, "whne": {} }]"#).unwrap_err();
+    assert!(error.to_string().starts_with("score_rules.json: "));
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use aram_mayhem_advisor::data::Champion;
+use aram_mayhem_advisor::scoring::{score_champion, shrink, wilson_interval, Confidence, PRIOR_GAMES, Z_95};
+
+use common::augment;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+fn champion(tier: &str, games: i32, win_rate: Option<f64>) -> Champion {
+    Champion { id: 1, tier: tier.to_string(), popularity: "1.00%".to_string(), games, win_rate }
+}
+
+#[test]
+fn wilson_interval_knows_nothing_without_games() {
+    assert_eq!(wilson_interval(0.6, 0, Z_95), (0.0, 1.0));
+    assert_eq!(wilson_interval(0.6, -5, Z_95), (0.0, 1.0));
+}
+
+#[test]
+fn wilson_interval_narrows_with_games() {
+    let (low, high) = wilson_interval(0.5, 100, Z_95);
+    assert!(close(low, 0.4038) && close(high, 0.5962), "{} {}", low, high);
+
+    let (low, high) = wilson_interval(0.5, 10_000, Z_95);
+    assert!(close(low, 0.4902) && close(high, 0.5098), "{} {}", low, high);
+
+    // Never leaves [0, 1], even for a perfect record.
+    let (low, high) = wilson_interval(1.0, 3, Z_95);
+    assert!(low > 0.0 && high == 1.0);
+}
+
+#[test]
+fn shrinkage_moves_from_the_prior_to_the_value() {
+    assert_eq!(shrink(6.0, 0, 2.0, PRIOR_GAMES), 2.0);
+    assert_eq!(shrink(6.0, PRIOR_GAMES as i32, 2.0, PRIOR_GAMES), 4.0);
+    assert!(shrink(6.0, 1_000_000, 2.0, PRIOR_GAMES) > 5.99);
+    assert_eq!(shrink(6.0, -10, 2.0, PRIOR_GAMES), 2.0);
+}
+
+#[test]
+fn champion_tier_leans_on_the_global_tier_when_few_games_back_it() {
+    let global = augment(1, "C");
+
+    let none = score_champion(&champion("S+", 0, None), &global);
+    assert_eq!((none.tier.as_str(), none.raw_tier.as_str()), ("C", "S+"));
+    assert_eq!(none.confidence, Confidence::Low);
+    assert_eq!(none.win_rate_interval, None);
+
+    // S+ (6) and C (2) weigh the same at PRIOR_GAMES.
+    let even = score_champion(&champion("S+", PRIOR_GAMES as i32, Some(0.55)), &global);
+    assert_eq!(even.score, 4.0);
+    assert_eq!(even.tier, "A");
+    assert_eq!(even.win_rate_interval, Some(wilson_interval(0.55, PRIOR_GAMES as i32, Z_95)));
+
+    let many = score_champion(&champion("S+", 50_000, None), &global);
+    assert_eq!(many.tier, "S+");
+    assert_eq!(many.confidence, Confidence::High);
+}
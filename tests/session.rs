@@ -0,0 +1,88 @@
+use aram_mayhem_advisor::session::{GameSession, Pick};
+
+const STABLE_FRAMES: usize = 3;
+const CLOSE_FRAMES: usize = 10;
+
+fn show(session: &mut GameSession, cards: [i32; 3], frames: usize) -> Option<Pick> {
+    (0..frames).map(|_| session.observe(Some(cards.map(Some)))).last().flatten()
+}
+
+fn close(session: &mut GameSession) -> Option<Pick> {
+    (0..CLOSE_FRAMES).filter_map(|_| session.observe(None)).last()
+}
+
+#[test]
+fn opens_only_once_the_cards_are_stable() {
+    let mut session = GameSession::new();
+
+    show(&mut session, [1, 2, 3], STABLE_FRAMES - 1);
+    assert!(!session.is_panel_open());
+
+    show(&mut session, [1, 2, 3], 1);
+    assert!(session.is_panel_open());
+}
+
+#[test]
+fn closing_records_the_offer_as_a_pick() {
+    let mut session = GameSession::new();
+    show(&mut session, [1, 2, 3], STABLE_FRAMES);
+
+    for _ in 1..CLOSE_FRAMES {
+        assert_eq!(session.observe(None), None);
+    }
+    assert_eq!(session.observe(None), Some(Pick::Ambiguous(vec![1, 2, 3])));
+    assert!(!session.is_panel_open());
+    assert!(session.picked_ids().is_empty());
+}
+
+#[test]
+fn a_reroll_replaces_the_offer() {
+    let mut session = GameSession::new();
+    show(&mut session, [1, 2, 3], STABLE_FRAMES);
+    show(&mut session, [1, 4, 3], STABLE_FRAMES);
+
+    assert_eq!(close(&mut session), Some(Pick::Ambiguous(vec![1, 3, 4])));
+}
+
+#[test]
+fn a_flicker_reopens_the_pick() {
+    let mut session = GameSession::new();
+    show(&mut session, [1, 2, 3], STABLE_FRAMES);
+    close(&mut session);
+    assert_eq!(session.picks().len(), 1);
+
+    // The same cards again: the panel was only hidden, e.g. by alt-tab.
+    show(&mut session, [3, 1, 2], STABLE_FRAMES);
+    assert!(session.picks().is_empty());
+    assert!(session.is_panel_open());
+
+    assert_eq!(close(&mut session), Some(Pick::Ambiguous(vec![1, 2, 3])));
+    assert_eq!(session.picks().len(), 1);
+}
+
+#[test]
+fn later_offers_eliminate_candidates() {
+    let mut session = GameSession::new();
+    show(&mut session, [1, 2, 3], STABLE_FRAMES);
+    close(&mut session);
+
+    show(&mut session, [1, 5, 6], STABLE_FRAMES);
+    assert_eq!(session.picks(), [Pick::Ambiguous(vec![2, 3])]);
+    close(&mut session);
+
+    show(&mut session, [3, 7, 8], STABLE_FRAMES);
+    assert_eq!(session.picks()[0], Pick::Known(2));
+    assert_eq!(session.picked_ids(), [2]);
+}
+
+#[test]
+fn drops_a_pick_whose_candidates_all_came_back() {
+    let mut session = GameSession::new();
+    show(&mut session, [1, 2, 3], STABLE_FRAMES);
+    close(&mut session);
+    show(&mut session, [4, 5, 6], STABLE_FRAMES);
+    close(&mut session);
+
+    show(&mut session, [1, 2, 3], STABLE_FRAMES);
+    assert_eq!(session.picks(), [Pick::Ambiguous(vec![4, 5, 6])]);
+}
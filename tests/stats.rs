@@ -0,0 +1,87 @@
+use aram_mayhem_advisor::data::{ChampionClass, MayhemData, CHAMPIONS_DATA};
+use aram_mayhem_advisor::scoring::Confidence;
+use aram_mayhem_advisor::stats::{resolve_stats, strong_synergies, StatsLevel};
+
+// Jinx and Caitlyn are marksmen with stats for augment 1; only Jinx has augment 2.
+fn data() -> MayhemData {
+    serde_json::from_str(
+        r#"{
+            "champions": {
+                "jinx": [
+                    { "id": 1, "tier": "S", "popularity": "10%", "games": 5000 },
+                    { "id": 2, "tier": "A", "popularity": "5%", "games": 3000 }
+                ],
+                "caitlyn": [{ "id": 1, "tier": "B", "popularity": "4%", "games": 4000 }]
+            },
+            "augments": [
+                { "id": 1, "name": { "ko_kr": "하나" }, "tier": "C", "games": 90000 },
+                { "id": 2, "name": { "ko_kr": "둘" }, "tier": "D", "games": 80000 },
+                { "id": 3, "name": { "ko_kr": "셋" }, "tier": "B", "games": 70000 },
+                { "id": 4, "name": { "ko_kr": "넷" } }
+            ],
+            "synergies": [
+                { "augments": [3, 1], "tier": "A", "games": 300 },
+                { "augments": [1, 2], "tier": "S", "games": 100 },
+                { "augments": [1, 4], "tier": "B", "games": 900 },
+                { "augments": [2, 3], "tier": "S+", "games": 50 }
+            ]
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn prefers_the_champions_own_stats() {
+    let data = data();
+    let resolved = resolve_stats(&data, &CHAMPIONS_DATA, "jinx", &data.augments[0]).unwrap();
+
+    assert_eq!(resolved.level, StatsLevel::Champion);
+    assert_eq!(resolved.stats.tier, "S");
+    assert_eq!(resolved.confidence(), Some(Confidence::High));
+}
+
+#[test]
+fn falls_back_to_the_primary_class() {
+    let data = data();
+    let resolved = resolve_stats(&data, &CHAMPIONS_DATA, "ashe", &data.augments[0]).unwrap();
+
+    assert_eq!(resolved.level, StatsLevel::Class);
+    assert_eq!(resolved.class, Some(ChampionClass::Marksman));
+    assert_eq!(resolved.stats.games, 9000);
+    // Plenty of games, but none of them Ashe's.
+    assert_eq!(resolved.confidence(), Some(Confidence::Low));
+}
+
+#[test]
+fn falls_back_to_the_augment_without_class_stats() {
+    let data = data();
+
+    let resolved = resolve_stats(&data, &CHAMPIONS_DATA, "lux", &data.augments[1]).unwrap();
+    assert_eq!(resolved.level, StatsLevel::Global);
+    assert_eq!(resolved.class, Some(ChampionClass::Mage));
+    assert_eq!(resolved.stats.tier, "D");
+    assert_eq!(resolved.confidence(), None);
+
+    let resolved = resolve_stats(&data, &CHAMPIONS_DATA, "jinx", &data.augments[2]).unwrap();
+    assert_eq!(resolved.level, StatsLevel::Global);
+    assert_eq!(resolved.confidence(), None);
+}
+
+#[test]
+fn resolves_nothing_for_an_unrated_augment() {
+    let data = data();
+    assert!(resolve_stats(&data, &CHAMPIONS_DATA, "lux", &data.augments[3]).is_none());
+}
+
+#[test]
+fn keeps_only_strong_synergies_with_picked_augments() {
+    let data = data();
+
+    let strong = strong_synergies(&data, 1, &[1, 2, 3, 4]);
+    let pairs: Vec<_> = strong.iter().map(|s| s.augments).collect();
+    // `B` falls below the badge threshold; the S+ pair does not involve augment 1.
+    assert_eq!(pairs, [[1, 2], [3, 1]]);
+
+    assert!(strong_synergies(&data, 4, &[1]).is_empty());
+    assert!(strong_synergies(&data, 1, &[]).is_empty());
+}
@@ -0,0 +1,53 @@
+use aram_mayhem_advisor::data::{Language, CHAMPIONS_DATA, MAYHEM_DATA};
+use aram_mayhem_advisor::teamcomp::{active_rules, default_rules, parse_rules, TeamComp};
+
+fn comp(allies: &[&str], enemies: &[&str]) -> TeamComp {
+    TeamComp {
+        allies: allies.iter().map(|s| s.to_string()).collect(),
+        enemies: enemies.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn default_rules_reference_known_augments_and_champions() {
+    let data = MAYHEM_DATA.get();
+    for rule in default_rules() {
+        for id in &rule.augments {
+            assert!(data.augments.iter().any(|a| a.id == *id), "{} lists unknown augment {}", rule.name, id);
+        }
+        for champion in &rule.when.champions {
+            assert!(CHAMPIONS_DATA.contains_key(champion), "{} lists unknown champion {}", rule.name, champion);
+        }
+        assert!(!rule.reason.get(Language::KoKr).is_empty(), "{} has no Korean reason", rule.name);
+    }
+}
+
+#[test]
+fn burst_rule_needs_two_enemy_assassins() {
+    let rules = default_rules();
+    let fired = |c: &TeamComp| active_rules(&rules, c, &CHAMPIONS_DATA).into_iter().map(|r| r.name).collect::<Vec<_>>();
+
+    assert!(!fired(&comp(&[], &["zed", "lux"])).contains(&"enemy-burst".to_string()));
+    assert!(fired(&comp(&[], &["zed", "talon"])).contains(&"enemy-burst".to_string()));
+    assert!(!fired(&comp(&["zed", "talon"], &[])).contains(&"enemy-burst".to_string()));
+}
+
+#[test]
+fn conditions_combine_filters() {
+    let rules = parse_rules(
+        "test",
+        r#"[{ "name": "ranged-magic-allies", "when": { "side": "ally", "damage_type": "magic", "range": "ranged", "at_least": 2 }, "augments": [1], "adjust": 1.0 }]"#,
+    )
+    .unwrap();
+    let condition = &rules[0].when;
+
+    assert_eq!(condition.count(&comp(&["lux", "xerath", "zed"], &[]), &CHAMPIONS_DATA), 2);
+    assert_eq!(condition.count(&comp(&["lux", "galio"], &[]), &CHAMPIONS_DATA), 1);
+    assert!(condition.matches(&comp(&["lux", "ziggs"], &["zed"]), &CHAMPIONS_DATA));
+}
+
+#[test]
+fn broken_rules_report_the_source() {
+    let error = parse_rules("team_rules.json", "[{").unwrap_err();
+    assert!(error.to_string().starts_with("team_rules.json: "));
+}
@@ -0,0 +1,77 @@
+use aram_mayhem_advisor::live_client::{AllGameData, EventKind};
+use aram_mayhem_advisor::timing::AugmentTiming;
+
+fn game(level: i32, game_time: f64, events: &str) -> AllGameData {
+    serde_json::from_str(&format!(
+        r#"{{ "activePlayer": {{ "level": {} }}, "allPlayers": [], "gameData": {{ "gameMode": "KIWI", "gameTime": {} }}, "events": {{ "Events": [{}] }} }}"#,
+        level, game_time, events
+    ))
+    .unwrap()
+}
+
+#[test]
+fn events_parse_into_kinds() {
+    let game = game(
+        1,
+        0.0,
+        r#"{ "EventID": 0, "EventName": "GameStart", "EventTime": 0.0 },
+           { "EventID": 1, "EventName": "ChampionKill", "EventTime": 95.0, "KillerName": "a", "VictimName": "b", "Assisters": ["c"] },
+           { "EventID": 2, "EventName": "Multikill", "EventTime": 96.0, "KillerName": "a", "KillStreak": 2 },
+           { "EventID": 3, "EventName": "Something", "EventTime": 97.0 }"#,
+    );
+    let kinds: Vec<EventKind> = game.events.events.iter().map(|e| e.kind()).collect();
+
+    assert_eq!(kinds[0], EventKind::GameStart);
+    assert_eq!(
+        kinds[1],
+        EventKind::ChampionKill { killer: "a".to_string(), victim: "b".to_string(), assisters: vec!["c".to_string()] }
+    );
+    assert_eq!(kinds[2], EventKind::Multikill { killer: "a".to_string(), streak: 2 });
+    assert_eq!(kinds[3], EventKind::Other("Something".to_string()));
+}
+
+#[test]
+fn window_opens_at_augment_levels_until_picked() {
+    let mut timing = AugmentTiming::new(&[3, 7]);
+
+    timing.update(&game(2, 60.0, ""));
+    assert!(!timing.is_open(0));
+
+    timing.update(&game(3, 90.0, ""));
+    assert!(timing.is_open(0));
+    assert!(!timing.is_open(1));
+
+    timing.update(&game(6, 300.0, ""));
+    assert!(!timing.is_open(1));
+
+    timing.update(&game(7, 400.0, ""));
+    assert!(timing.is_open(1));
+}
+
+#[test]
+fn window_times_out_without_a_pick() {
+    let mut timing = AugmentTiming::new(&[3]);
+
+    timing.update(&game(3, 90.0, ""));
+    assert!(timing.is_open(0));
+
+    timing.update(&game(4, 300.0, ""));
+    assert!(!timing.is_open(0));
+    // Still unpicked, so a slow fallback scan keeps looking.
+    assert!(timing.is_pending(0));
+    assert!(!timing.is_pending(1));
+
+    timing.update(&game(4, 400.0, r#"{ "EventID": 9, "EventName": "GameEnd", "EventTime": 400.0, "Result": "Win" }"#));
+    assert!(!timing.is_pending(0));
+}
+
+#[test]
+fn unknown_level_keeps_ocr_on_until_the_game_ends() {
+    let mut timing = AugmentTiming::new(&[3]);
+
+    timing.update(&game(0, 10.0, ""));
+    assert!(timing.is_open(0));
+
+    timing.update(&game(0, 900.0, r#"{ "EventID": 9, "EventName": "GameEnd", "EventTime": 900.0, "Result": "Win" }"#));
+    assert!(!timing.is_open(0));
+}
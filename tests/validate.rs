@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use aram_mayhem_advisor::compiled::LANGUAGE_KEYS;
+use aram_mayhem_advisor::data::{ChampionInfo, Language, MayhemData, NO_CHAMPION};
+use aram_mayhem_advisor::validate::{validate, IssueKind, Severity};
+use serde_json::{json, Value};
+
+fn name(text: &str) -> Value {
+    LANGUAGE_KEYS.iter().map(|key| (key.to_string(), json!(format!("{} {}", text, key)))).collect()
+}
+
+fn augment(id: i32) -> Value {
+    json!({ "id": id, "name": name(&format!("augment {}", id)), "tier": "A", "popularity": "1.00%", "games": 10 })
+}
+
+fn stats(id: i32) -> Value {
+    json!({ "id": id, "tier": "B", "popularity": "2.00%", "games": 5, "win_rate": 0.5 })
+}
+
+// Two augments, Lux with stats for both and a synergy between them; no issues at all.
+fn mayhem() -> Value {
+    json!({
+        "champions": { "lux": [stats(1), stats(2)] },
+        "augments": [augment(1), augment(2)],
+        "synergies": [{ "augments": [1, 2], "tier": "S", "games": 3 }]
+    })
+}
+
+fn champions() -> Value {
+    json!({
+        "lux": { "name": name("Lux"), "key": 99 },
+        NO_CHAMPION: { "name": name("None") }
+    })
+}
+
+fn issues(mayhem: Value, champions: Value) -> Vec<(Severity, IssueKind)> {
+    let mayhem: MayhemData = serde_json::from_value(mayhem).unwrap();
+    let champions: HashMap<String, ChampionInfo> = serde_json::from_value(champions).unwrap();
+    validate(&mayhem, &champions).issues.into_iter().map(|i| (i.severity, i.kind)).collect()
+}
+
+#[test]
+fn accepts_clean_data_and_the_none_champion() {
+    assert_eq!(issues(mayhem(), champions()), []);
+}
+
+#[test]
+fn reports_duplicate_and_dangling_ids() {
+    let mut data = mayhem();
+    data["augments"].as_array_mut().unwrap().push(augment(2));
+    data["champions"]["lux"].as_array_mut().unwrap().extend([stats(1), stats(7)]);
+    data["synergies"].as_array_mut().unwrap().extend([
+        json!({ "augments": [2, 1], "tier": "A", "games": 1 }),
+        json!({ "augments": [1, 8], "tier": "A", "games": 1 }),
+    ]);
+
+    let issues = issues(data, champions());
+    let kinds: Vec<_> = issues.iter().filter(|(s, _)| *s == Severity::Error).map(|(_, k)| k.clone()).collect();
+    assert!(kinds.contains(&IssueKind::DuplicateAugmentId { id: 2 }));
+    assert!(kinds.contains(&IssueKind::DuplicateChampionAugment { champion: "lux".to_string(), id: 1 }));
+    assert!(kinds.contains(&IssueKind::DanglingAugmentId { champion: "lux".to_string(), id: 7 }));
+    assert!(kinds.contains(&IssueKind::DuplicateSynergy { pair: [2, 1] }));
+    assert!(kinds.contains(&IssueKind::DanglingSynergyAugment { pair: [1, 8], id: 8 }));
+}
+
+#[test]
+fn reports_champions_missing_on_either_side() {
+    let mut data = mayhem();
+    data["champions"]["teemo"] = json!([stats(1)]);
+    let mut info = champions();
+    info["jinx"] = json!({ "name": name("Jinx"), "key": 99 });
+
+    assert_eq!(issues(data, info), [
+        (Severity::Error, IssueKind::UnknownChampion { champion: "teemo".to_string() }),
+        (Severity::Warning, IssueKind::ChampionWithoutStats { champion: "jinx".to_string() }),
+        (Severity::Error, IssueKind::DuplicateChampionKey { key: 99, champions: vec!["jinx".to_string(), "lux".to_string()] }),
+    ]);
+}
+
+#[test]
+fn reports_missing_and_shared_names() {
+    let mut data = mayhem();
+    data["augments"][0]["name"]["ja_jp"] = json!(" ");
+    data["augments"][1]["name"]["ko_kr"] = data["augments"][0]["name"]["ko_kr"].clone();
+
+    assert_eq!(issues(data, champions()), [
+        (Severity::Error, IssueKind::MissingTranslation { owner: "augment 1".to_string(), language: Language::JaJp }),
+        (Severity::Warning, IssueKind::DuplicateName {
+            language: Language::KoKr,
+            name: "augment 1 ko_kr".to_string(),
+            ids: vec!["augment 1".to_string(), "augment 2".to_string()],
+        }),
+    ]);
+}
+
+#[test]
+fn reports_out_of_range_stats() {
+    let mut data = mayhem();
+    data["augments"][0]["tier"] = json!("F");
+    data["augments"][0]["popularity"] = json!("120%");
+    data["champions"]["lux"][0]["games"] = json!(-1);
+    data["champions"]["lux"][1]["win_rate"] = json!(1.5);
+
+    assert_eq!(issues(data, champions()), [
+        (Severity::Error, IssueKind::InvalidTier { owner: "augment 1".to_string(), tier: "F".to_string() }),
+        (Severity::Error, IssueKind::InvalidPopularity { owner: "augment 1".to_string(), popularity: "120%".to_string() }),
+        (Severity::Error, IssueKind::InvalidGames { owner: "lux/1".to_string(), games: -1 }),
+        (Severity::Error, IssueKind::InvalidWinRate { owner: "lux/2".to_string(), win_rate: 1.5 }),
+    ]);
+}